@@ -0,0 +1,378 @@
+use serde::{de, Deserialize, Deserializer};
+use std::{collections::BTreeMap, error::Error, fmt, str::FromStr};
+
+#[derive(Debug, Deserialize)]
+pub struct Star {
+    pub get_star_ts: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    pub stars: usize,
+    pub local_score: usize,
+    pub last_star_ts: u64,
+    // Day, then part
+    pub completion_day_level: BTreeMap<u32, BTreeMap<u32, Star>>,
+}
+
+impl Member {
+    // Members without a name are shown the way the leaderboard page does
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    fn star_time(&self, day: u32, part: u32) -> Option<u64> {
+        Some(self.completion_day_level.get(&day)?.get(&part)?.get_star_ts)
+    }
+}
+
+fn year<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    String::deserialize(deserializer)?
+        .parse()
+        .map_err(de::Error::custom)
+}
+
+// The JSON export of a private leaderboard
+#[derive(Debug, Deserialize)]
+pub struct Leaderboard {
+    #[serde(rename = "event", deserialize_with = "year")]
+    pub year: u64,
+    pub members: BTreeMap<u64, Member>,
+}
+
+impl FromStr for Leaderboard {
+    type Err = serde_json::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(s)
+    }
+}
+
+// Seconds it took to get each star of a day, counted from the puzzle unlock
+#[derive(Debug, PartialEq)]
+pub struct DayTimes {
+    pub day: u32,
+    pub part_1: Option<u64>,
+    pub part_2: Option<u64>,
+}
+
+impl DayTimes {
+    pub fn delta(&self) -> Option<u64> {
+        Some(self.part_2?.saturating_sub(self.part_1?))
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Scoring {
+    // The points given by the leaderboard: for every star, as many points as
+    // members for the first to get it, one less for the second, and so on
+    LocalScore,
+    Stars,
+    // Median of the times of every star, members without stars come last
+    MedianTime,
+}
+
+impl fmt::Display for Scoring {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Scoring::LocalScore => write!(f, "local score"),
+            Scoring::Stars => write!(f, "stars"),
+            Scoring::MedianTime => write!(f, "median time"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ParseScoringError(String);
+
+impl fmt::Display for ParseScoringError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "unknown scoring `{}`, expected local, stars or median",
+            self.0
+        )
+    }
+}
+
+impl Error for ParseScoringError {}
+
+impl FromStr for Scoring {
+    type Err = ParseScoringError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "local" => Ok(Scoring::LocalScore),
+            "stars" => Ok(Scoring::Stars),
+            "median" => Ok(Scoring::MedianTime),
+            _ => Err(ParseScoringError(s.to_string())),
+        }
+    }
+}
+
+fn is_leap_year(year: u64) -> bool {
+    (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400)
+}
+
+// Puzzles unlock at midnight EST (UTC-5)
+pub fn unlock_time(year: u64, day: u32) -> u64 {
+    let days_before_year = (1970..year)
+        .map(|year| if is_leap_year(year) { 366 } else { 365 })
+        .sum::<u64>();
+    let days_before_december = 334 + u64::from(is_leap_year(year));
+
+    (days_before_year + days_before_december + u64::from(day) - 1) * 24 * 3600 + 5 * 3600
+}
+
+pub fn format_duration(seconds: u64) -> String {
+    let (days, seconds) = (seconds / (24 * 3600), seconds % (24 * 3600));
+    let time = format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    );
+
+    if days == 0 {
+        time
+    } else {
+        format!("{days}d {time}")
+    }
+}
+
+impl Leaderboard {
+    pub fn day_times(&self, member: &Member, day: u32) -> DayTimes {
+        let unlock = unlock_time(self.year, day);
+        let time = |part| Some(member.star_time(day, part)?.saturating_sub(unlock));
+
+        DayTimes {
+            day,
+            part_1: time(1),
+            part_2: time(2),
+        }
+    }
+
+    // Every day the member got a star on
+    pub fn completion_times(&self, member: &Member) -> Vec<DayTimes> {
+        member
+            .completion_day_level
+            .keys()
+            .map(|day| self.day_times(member, *day))
+            .collect()
+    }
+
+    pub fn median_time(&self, member: &Member) -> Option<u64> {
+        let mut times = self
+            .completion_times(member)
+            .into_iter()
+            .flat_map(|times| [times.part_1, times.part_2])
+            .flatten()
+            .collect::<Vec<_>>();
+        times.sort_unstable();
+
+        match times.len() {
+            0 => None,
+            len if !len.is_multiple_of(2) => Some(times[len / 2]),
+            len => Some((times[len / 2 - 1] + times[len / 2]) / 2),
+        }
+    }
+
+    // Ties are broken like on the leaderboard page, by who got their last star
+    // first
+    pub fn standings(&self, scoring: Scoring) -> Vec<&Member> {
+        let mut members = self.members.values().collect::<Vec<_>>();
+        members.sort_by_key(|member| member.last_star_ts);
+
+        match scoring {
+            Scoring::LocalScore => {
+                members.sort_by_key(|member| std::cmp::Reverse(member.local_score))
+            }
+            Scoring::Stars => members.sort_by_key(|member| std::cmp::Reverse(member.stars)),
+            Scoring::MedianTime => members.sort_by_key(|member| {
+                let median = self.median_time(member);
+                (median.is_none(), median)
+            }),
+        }
+
+        members
+    }
+
+    // Members with a star on the day, those with both stars first, in the order
+    // they got them
+    pub fn day_standings(&self, day: u32) -> Vec<(&Member, DayTimes)> {
+        let mut standings = self
+            .members
+            .values()
+            .filter(|member| member.completion_day_level.contains_key(&day))
+            .map(|member| (member, self.day_times(member, day)))
+            .collect::<Vec<_>>();
+        standings.sort_by_key(|(_, times)| (times.part_2.is_none(), times.part_2, times.part_1));
+
+        standings
+    }
+
+    pub fn days(&self) -> Vec<u32> {
+        let mut days = self
+            .members
+            .values()
+            .flat_map(|member| member.completion_day_level.keys().copied())
+            .collect::<Vec<_>>();
+        days.sort_unstable();
+        days.dedup();
+
+        days
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SAMPLE_DATA: &str = include_str!("../fixtures/leaderboard.json");
+
+    fn ids(members: Vec<&Member>) -> Vec<u64> {
+        members.into_iter().map(|member| member.id).collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        let result = SAMPLE_DATA.parse::<Leaderboard>().unwrap();
+
+        assert_eq!(result.year, 2023);
+        assert_eq!(result.members.len(), 4);
+        assert_eq!(result.members[&1001].display_name(), "Ada");
+        assert_eq!(
+            result.members[&1003].display_name(),
+            "(anonymous user #1003)"
+        );
+        assert_eq!(result.members[&1002].star_time(2, 1), Some(1701580000));
+        assert_eq!(result.members[&1002].star_time(2, 2), None);
+
+        assert!(r#"{"event": "twenty", "members": {}}"#.parse::<Leaderboard>().is_err());
+    }
+
+    #[test]
+    fn test_unlock_time() {
+        assert_eq!(unlock_time(2023, 1), 1701406800);
+        assert_eq!(unlock_time(2023, 25), 1703480400);
+        assert_eq!(unlock_time(2024, 1), 1733029200);
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(0), "00:00:00");
+        assert_eq!(format_duration(3800), "01:03:20");
+        assert_eq!(format_duration(86800), "1d 00:06:40");
+    }
+
+    #[test]
+    fn test_completion_times() {
+        let leaderboard = SAMPLE_DATA.parse::<Leaderboard>().unwrap();
+
+        let result = leaderboard.completion_times(&leaderboard.members[&1002]);
+
+        assert_eq!(
+            result,
+            vec![
+                DayTimes {
+                    day: 1,
+                    part_1: Some(200),
+                    part_2: Some(4000)
+                },
+                DayTimes {
+                    day: 2,
+                    part_1: Some(86800),
+                    part_2: None
+                },
+            ]
+        );
+        assert_eq!(
+            result.iter().map(DayTimes::delta).collect::<Vec<_>>(),
+            vec![Some(3800), None]
+        );
+        assert!(leaderboard
+            .completion_times(&leaderboard.members[&1004])
+            .is_empty());
+    }
+
+    #[test]
+    fn test_median_time() {
+        let leaderboard = SAMPLE_DATA.parse::<Leaderboard>().unwrap();
+
+        let result = leaderboard
+            .members
+            .values()
+            .map(|member| leaderboard.median_time(member))
+            .collect::<Vec<_>>();
+
+        assert_eq!(result, vec![Some(600), Some(4000), Some(100), None]);
+    }
+
+    #[test]
+    fn test_standings() {
+        let leaderboard = SAMPLE_DATA.parse::<Leaderboard>().unwrap();
+
+        assert_eq!(
+            ids(leaderboard.standings(Scoring::LocalScore)),
+            vec![1001, 1002, 1003, 1004]
+        );
+        assert_eq!(
+            ids(leaderboard.standings(Scoring::Stars)),
+            vec![1001, 1002, 1003, 1004]
+        );
+        assert_eq!(
+            ids(leaderboard.standings(Scoring::MedianTime)),
+            vec![1003, 1001, 1002, 1004]
+        );
+    }
+
+    #[test]
+    fn test_standings_ties() {
+        let leaderboard = r#"{"event": "2023", "members": {
+            "1": {"id": 1, "name": "a", "stars": 1, "local_score": 2, "last_star_ts": 1701407000,
+                "completion_day_level": {"1": {"1": {"get_star_ts": 1701407000}}}},
+            "2": {"id": 2, "name": "b", "stars": 1, "local_score": 2, "last_star_ts": 1701406900,
+                "completion_day_level": {"1": {"1": {"get_star_ts": 1701406900}}}}
+        }}"#
+        .parse::<Leaderboard>()
+        .unwrap();
+
+        assert_eq!(ids(leaderboard.standings(Scoring::LocalScore)), vec![2, 1]);
+        assert_eq!(ids(leaderboard.standings(Scoring::Stars)), vec![2, 1]);
+    }
+
+    #[test]
+    fn test_day_standings() {
+        let leaderboard = SAMPLE_DATA.parse::<Leaderboard>().unwrap();
+
+        let result = leaderboard
+            .day_standings(1)
+            .into_iter()
+            .map(|(member, times)| (member.id, times.part_1, times.part_2))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            result,
+            vec![
+                (1001, Some(300), Some(600)),
+                (1002, Some(200), Some(4000)),
+                (1003, Some(100), None),
+            ]
+        );
+        assert_eq!(leaderboard.days(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_parse_scoring() {
+        assert_eq!("median".parse(), Ok(Scoring::MedianTime));
+        assert_eq!(
+            "fastest".parse::<Scoring>(),
+            Err(ParseScoringError("fastest".to_string()))
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use aoc::{
+    leaderboard::{format_duration, Leaderboard, Scoring},
+    samples,
+};
+use std::{fs, path::Path};
+
+const USAGE: &str = "usage: aoc samples <puzzle.html> <day directory>
+       aoc leaderboard <leaderboard.json> [--scoring local|stars|median]";
+
+fn format_time(seconds: Option<u64>) -> String {
+    seconds.map_or("-".to_string(), format_duration)
+}
+
+fn samples(page: &str, day: &str) {
+    let parts = samples::parse_puzzle(&fs::read_to_string(page).unwrap());
+    if parts.is_empty() {
+        panic!("no puzzle description found in {page}");
+    }
+    samples::write_day(&parts, Path::new(day)).unwrap();
+
+    for (index, part) in parts.iter().enumerate() {
+        let answer = match (&part.answer, part.expected_answer()) {
+            (Some(answer), Some(_)) => answer.clone(),
+            (Some(answer), None) => format!("{answer} (not a number, the test stub expects 0)"),
+            (None, _) => "not found (the test stub expects 0)".to_string(),
+        };
+        println!(
+            "Part {}: {} example(s), sample answer {answer}",
+            index + 1,
+            part.samples.len(),
+        );
+    }
+}
+
+fn leaderboard(path: &str, scoring: Scoring) {
+    let leaderboard = fs::read_to_string(path)
+        .unwrap()
+        .parse::<Leaderboard>()
+        .unwrap_or_else(|error| panic!("{error}"));
+
+    // Format widths count characters, so names line up whatever their script
+    let name_width = leaderboard
+        .members
+        .values()
+        .map(|member| member.display_name().chars().count())
+        .chain(["member".len()])
+        .max()
+        .unwrap();
+
+    println!("Standings by {scoring}");
+    println!(
+        "{:>4}  {:<name_width$}  {:>11}  {:>5}  {:>11}",
+        "rank", "member", "local score", "stars", "median time"
+    );
+    for (index, member) in leaderboard.standings(scoring).into_iter().enumerate() {
+        println!(
+            "{:>4}  {:<name_width$}  {:>11}  {:>5}  {:>11}",
+            index + 1,
+            member.display_name(),
+            member.local_score,
+            member.stars,
+            format_time(leaderboard.median_time(member)),
+        );
+    }
+
+    for day in leaderboard.days() {
+        println!();
+        println!("Day {day}");
+        println!(
+            "{:<name_width$}  {:>11}  {:>11}  {:>11}",
+            "member", "part 1", "part 2", "delta"
+        );
+        for (member, times) in leaderboard.day_standings(day) {
+            println!(
+                "{:<name_width$}  {:>11}  {:>11}  {:>11}",
+                member.display_name(),
+                format_time(times.part_1),
+                format_time(times.part_2),
+                format_time(times.delta()),
+            );
+        }
+    }
+}
+
+fn main() {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+
+    match &args.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        ["samples", page, day] => samples(page, day),
+        ["leaderboard", path] => leaderboard(path, Scoring::LocalScore),
+        ["leaderboard", path, "--scoring", scoring] => leaderboard(
+            path,
+            scoring.parse().unwrap_or_else(|error| panic!("{error}")),
+        ),
+        _ => panic!("{USAGE}"),
+    }
+}
@@ -0,0 +1,333 @@
+use std::{fs, io, path::Path};
+
+// Example inputs and expected sample answer of one part of a puzzle, as found
+// in its `<article class="day-desc">`
+#[derive(Debug, PartialEq)]
+pub struct Part {
+    pub samples: Vec<String>,
+    pub answer: Option<String>,
+}
+
+impl Part {
+    // The stubs solve into a usize, so only numeric answers can be asserted
+    pub fn expected_answer(&self) -> Option<usize> {
+        self.answer.as_deref()?.parse().ok()
+    }
+}
+
+// The text between every `open` and the following `close`
+fn segments<'a>(mut html: &'a str, open: &'a str, close: &'a str) -> impl Iterator<Item = &'a str> {
+    std::iter::from_fn(move || {
+        let start = html.find(open)? + open.len();
+        let end = start + html[start..].find(close)?;
+        let segment = &html[start..end];
+        html = &html[end + close.len()..];
+
+        Some(segment)
+    })
+}
+
+fn entity(name: &str) -> Option<char> {
+    match name {
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "amp" => Some('&'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        _ => {
+            let code = name.strip_prefix('#')?;
+            let code = match code.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => code.parse().ok()?,
+            };
+            char::from_u32(code)
+        }
+    }
+}
+
+// Unknown entities are kept as they are
+fn decode_entities(html: &str) -> String {
+    let mut decoded = String::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        match rest
+            .find(';')
+            .and_then(|end| Some((entity(&rest[1..end])?, end)))
+        {
+            Some((character, end)) => {
+                decoded.push(character);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+
+    decoded
+}
+
+// Drops the tags, like the `<em>` highlighting parts of an example
+fn text(html: &str) -> String {
+    let mut text = String::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        text.push_str(&rest[..start]);
+        rest = rest[start..]
+            .find('>')
+            .map_or("", |end| &rest[start + end + 1..]);
+    }
+    text.push_str(rest);
+
+    decode_entities(&text)
+}
+
+// The sample answer is the last emphasized code of the part, both nestings of
+// `<code>` and `<em>` are found on puzzle pages
+fn parse_part(article: &str) -> Part {
+    let samples = segments(article, "<pre><code>", "</code></pre>")
+        .map(text)
+        .collect();
+
+    let article = article
+        .replace("<em><code>", "<code><em>")
+        .replace("</code></em>", "</em></code>");
+    let answer = segments(&article, "<code><em>", "</em></code>")
+        .last()
+        .map(text);
+
+    Part { samples, answer }
+}
+
+// One part per unlocked part of the puzzle page
+pub fn parse_puzzle(html: &str) -> Vec<Part> {
+    segments(html, "<article", "</article>")
+        .map(|article| parse_part(article.split_once('>').map_or("", |(_, body)| body)))
+        .collect()
+}
+
+// Only the first example of a part is its sample input, the later ones
+// usually show intermediate steps. A part without an example (or not unlocked
+// yet) is tested against the sample of the part before it.
+pub fn render_lib(parts: &[Part]) -> String {
+    let mut constants = vec![];
+    let mut tests = vec![];
+    let mut sample = None;
+
+    for number in 1..=2 {
+        let part = parts.get(number - 1);
+
+        if part.is_some_and(|part| !part.samples.is_empty()) {
+            constants.push(format!(
+                "    const SAMPLE_DATA_{number}: &str = include_str!(\"../samples/part_{number}.txt\");"
+            ));
+            sample = Some(number);
+        }
+        let sample_number = *sample.get_or_insert_with(|| {
+            constants.push(format!("    const SAMPLE_DATA_{number}: &str = r#\"\"#;"));
+            number
+        });
+
+        let comment = match part.map(|part| (part.answer.as_deref(), part.expected_answer())) {
+            Some((Some(answer), None)) => {
+                format!("        // Sample answer on the puzzle page: {answer}\n")
+            }
+            Some((None, _)) => "        // No sample answer found on the puzzle page\n".to_string(),
+            _ => String::new(),
+        };
+        let answer = part.and_then(Part::expected_answer).unwrap_or(0);
+        tests.push(format!(
+            "    #[test]
+    fn test_part_{number}() {{
+{comment}        assert_eq!(solve_part_{number}(SAMPLE_DATA_{sample_number}), {answer});
+    }}"
+        ));
+    }
+
+    format!(
+        "pub fn solve_part_1(file_content: &str) -> usize {{
+    0
+}}
+
+pub fn solve_part_2(file_content: &str) -> usize {{
+    0
+}}
+
+#[cfg(test)]
+mod test {{
+    use super::*;
+
+{}
+
+{}
+}}
+",
+        constants.join("\n"),
+        tests.join("\n\n")
+    )
+}
+
+// Writes `samples/part_<n>.txt` for every part with an example, and the
+// `src/lib.rs` stub testing the solvers against them
+pub fn write_day(parts: &[Part], day: &Path) -> io::Result<()> {
+    let samples = day.join("samples");
+    fs::create_dir_all(&samples)?;
+
+    for (index, part) in parts.iter().enumerate() {
+        if let Some(data) = part.samples.first() {
+            fs::write(samples.join(format!("part_{}.txt", index + 1)), data)?;
+        }
+    }
+
+    fs::write(day.join("src").join("lib.rs"), render_lib(parts))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const DAY_1: &str = include_str!("../fixtures/day1.html");
+    const DAY_3_PART_1: &str = include_str!("../fixtures/day3_part_1.html");
+    const DAY_8: &str = include_str!("../fixtures/day8.html");
+    const DAY_9: &str = include_str!("../fixtures/day9.html");
+
+    #[test]
+    fn test_text() {
+        assert_eq!(text("0 3 <em>18</em>"), "0 3 18");
+        assert_eq!(text("a &lt; b &amp;&amp; c&gt;d"), "a < b && c>d");
+        assert_eq!(text("&#35;&#x41;&quot;&apos;"), "#A\"'");
+        assert_eq!(text("&minus; & &;"), "&minus; & &;");
+    }
+
+    #[test]
+    fn test_parse_puzzle() {
+        let result = parse_puzzle(DAY_1);
+
+        assert_eq!(
+            result,
+            vec![
+                Part {
+                    samples: vec!["1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n".to_string()],
+                    answer: Some("142".to_string()),
+                },
+                Part {
+                    samples: vec!["two1nine\neightwothree\nabcone2threexyz\nxtwone3four\n4nineeightseven2\nzoneight234\n7pqrstsixteen\n".to_string()],
+                    answer: Some("281".to_string()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_puzzle_part_1_only() {
+        let result = parse_puzzle(DAY_3_PART_1);
+
+        assert_eq!(result.len(), 1);
+        assert!(result[0].samples[0].starts_with("467..114..\n"));
+        assert_eq!(result[0].answer, Some("4361".to_string()));
+    }
+
+    #[test]
+    fn test_parse_puzzle_several_samples() {
+        let result = parse_puzzle(DAY_8);
+
+        assert_eq!(
+            result
+                .iter()
+                .map(|part| (part.samples.len(), part.answer.as_deref()))
+                .collect::<Vec<_>>(),
+            vec![(2, Some("6")), (1, Some("6"))]
+        );
+        assert!(result[0].samples[1].starts_with("LLR\n"));
+    }
+
+    #[test]
+    fn test_parse_puzzle_highlighted_sample() {
+        let result = parse_puzzle(DAY_9);
+
+        assert_eq!(
+            result[0].samples[1],
+            "0   3   6   9  12  15  18\n  3   3   3   3   3   3\n    0   0   0   0   0\n"
+        );
+        assert_eq!(result[0].answer, Some("114".to_string()));
+        assert!(result[1].samples.is_empty());
+        assert_eq!(result[1].answer, Some("2".to_string()));
+    }
+
+    #[test]
+    fn test_render_lib() {
+        let result = render_lib(&parse_puzzle(DAY_9));
+
+        assert!(result.contains(
+            "    const SAMPLE_DATA_1: &str = include_str!(\"../samples/part_1.txt\");\n\n"
+        ));
+        assert!(result.contains("assert_eq!(solve_part_1(SAMPLE_DATA_1), 114);"));
+        assert!(result.contains("assert_eq!(solve_part_2(SAMPLE_DATA_1), 2);"));
+        assert!(!result.contains("//"));
+    }
+
+    #[test]
+    fn test_render_lib_without_numeric_answer() {
+        let parts = [
+            Part {
+                samples: vec!["    [D]\n[N] [C]\n".to_string()],
+                answer: Some("CMZ".to_string()),
+            },
+            Part {
+                samples: vec![],
+                answer: None,
+            },
+        ];
+
+        let result = render_lib(&parts);
+
+        assert!(result.contains(
+            "        // Sample answer on the puzzle page: CMZ
+        assert_eq!(solve_part_1(SAMPLE_DATA_1), 0);"
+        ));
+        assert!(result.contains(
+            "        // No sample answer found on the puzzle page
+        assert_eq!(solve_part_2(SAMPLE_DATA_1), 0);"
+        ));
+    }
+
+    #[test]
+    fn test_render_lib_without_samples() {
+        let result = render_lib(&[]);
+
+        assert!(result.contains("const SAMPLE_DATA_1: &str = r#\"\"#;\n\n"));
+        assert!(result.contains("assert_eq!(solve_part_1(SAMPLE_DATA_1), 0);"));
+        assert!(result.contains("assert_eq!(solve_part_2(SAMPLE_DATA_1), 0);"));
+    }
+
+    #[test]
+    fn test_write_day() {
+        let day = std::env::temp_dir().join(format!("aoc_samples_{}", std::process::id()));
+        fs::create_dir_all(day.join("src")).unwrap();
+
+        let parts = parse_puzzle(DAY_1);
+        write_day(&parts, &day).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(day.join("samples/part_1.txt")).unwrap(),
+            parts[0].samples[0]
+        );
+        assert_eq!(
+            fs::read_to_string(day.join("samples/part_2.txt")).unwrap(),
+            parts[1].samples[0]
+        );
+        assert_eq!(
+            fs::read_to_string(day.join("src/lib.rs")).unwrap(),
+            render_lib(&parts)
+        );
+
+        fs::remove_dir_all(day).unwrap();
+    }
+}
@@ -0,0 +1,60 @@
+use day1::{diagnose_part_1, diagnose_part_2, LineDiagnostic, MissingDigitPolicy, Token};
+
+const FIRST_COLOR: &str = "\x1b[1;32m";
+const LAST_COLOR: &str = "\x1b[1;36m";
+const RESET: &str = "\x1b[0m";
+
+fn highlight(line: &str, first: &Token, last: &Token) -> String {
+    if first.span.end > last.span.start {
+        return format!(
+            "{}{FIRST_COLOR}{}{RESET}{}",
+            &line[..first.span.start],
+            &line[first.span.start..last.span.end.max(first.span.end)],
+            &line[last.span.end.max(first.span.end)..]
+        );
+    }
+
+    format!(
+        "{}{FIRST_COLOR}{}{RESET}{}{LAST_COLOR}{}{RESET}{}",
+        &line[..first.span.start],
+        &line[first.span.clone()],
+        &line[first.span.end..last.span.start],
+        &line[last.span.clone()],
+        &line[last.span.end..]
+    )
+}
+
+fn main() {
+    let part = std::env::args().nth(1).unwrap_or_else(|| "2".to_string());
+    let file_content = include_str!("../../input");
+
+    let diagnostics = match part.as_str() {
+        "1" => diagnose_part_1(file_content, MissingDigitPolicy::Zero),
+        "2" => diagnose_part_2(file_content, MissingDigitPolicy::Zero),
+        _ => panic!("unknown part {part}, expected 1 or 2"),
+    }
+    .unwrap();
+
+    for diagnostic @ LineDiagnostic {
+        line_number,
+        line,
+        tokens,
+    } in &diagnostics
+    {
+        match tokens {
+            Some((first, last)) => println!(
+                "{line_number:>5}: {} -> {}",
+                highlight(line, first, last),
+                diagnostic.calibration_value()
+            ),
+            None => println!("{line_number:>5}: {line} -> no digits"),
+        }
+    }
+
+    let answer = diagnostics
+        .iter()
+        .map(LineDiagnostic::calibration_value)
+        .sum::<usize>();
+
+    println!("Answer part {part}: {answer}");
+}
@@ -0,0 +1,57 @@
+use day1::{solve_part_1_bytes, MissingDigitError, MissingDigitPolicy};
+use std::{fs::File, io::Read};
+
+const CHUNK_SIZE: usize = 64 * 1024 * 1024;
+
+// Solves the complete lines read so far and carries the partial last one over
+// to the next read, so that files larger than memory can be streamed
+fn solve_stream(
+    mut reader: impl Read,
+    policy: MissingDigitPolicy,
+) -> Result<usize, MissingDigitError> {
+    let mut buffer = vec![0; CHUNK_SIZE];
+    let mut filled = 0;
+    let mut lines_before = 0;
+    let mut sum = 0;
+
+    loop {
+        let read = reader.read(&mut buffer[filled..]).unwrap();
+        let end_of_file = read == 0;
+        filled += read;
+
+        let complete = match memchr::memrchr(b'\n', &buffer[..filled]) {
+            _ if end_of_file => filled,
+            Some(index) => index + 1,
+            None => {
+                // A single line longer than the buffer
+                if filled == buffer.len() {
+                    buffer.resize(buffer.len() * 2, 0);
+                }
+                continue;
+            }
+        };
+
+        let chunk = &buffer[..complete];
+        sum += solve_part_1_bytes(chunk, policy).map_err(|error| MissingDigitError {
+            line_number: lines_before + error.line_number,
+        })?;
+        lines_before += memchr::memchr_iter(b'\n', chunk).count();
+
+        buffer.copy_within(complete..filled, 0);
+        filled -= complete;
+
+        if end_of_file {
+            return Ok(sum);
+        }
+    }
+}
+
+fn main() {
+    let answer = match std::env::args().nth(1) {
+        Some(path) => solve_stream(File::open(path).unwrap(), MissingDigitPolicy::Error),
+        None => solve_part_1_bytes(include_bytes!("../../input"), MissingDigitPolicy::Error),
+    }
+    .unwrap_or_else(|error| panic!("{error}"));
+
+    println!("Answer part 1: {answer}");
+}
@@ -1,76 +1,839 @@
-use std::borrow::Cow;
+use aho_corasick::{AhoCorasick, Match};
+use std::{error::Error, fmt, fs, io, iter, ops::Range, path::Path, str};
 
-const STRING_NUMBERS: &[&str] = &[
-    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+const DIGITS: &[&str] = &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
+
+// Zero of every non-ASCII run of Unicode decimal digits (general category Nd),
+// the other nine digits of a run always follow it contiguously
+const UNICODE_ZERO_DIGITS: &[char] = &[
+    '\u{660}',
+    '\u{6F0}',
+    '\u{7C0}',
+    '\u{966}',
+    '\u{9E6}',
+    '\u{A66}',
+    '\u{AE6}',
+    '\u{B66}',
+    '\u{BE6}',
+    '\u{C66}',
+    '\u{CE6}',
+    '\u{D66}',
+    '\u{DE6}',
+    '\u{E50}',
+    '\u{ED0}',
+    '\u{F20}',
+    '\u{1040}',
+    '\u{1090}',
+    '\u{17E0}',
+    '\u{1810}',
+    '\u{1946}',
+    '\u{19D0}',
+    '\u{1A80}',
+    '\u{1A90}',
+    '\u{1B50}',
+    '\u{1BB0}',
+    '\u{1C40}',
+    '\u{1C50}',
+    '\u{A620}',
+    '\u{A8D0}',
+    '\u{A900}',
+    '\u{A9D0}',
+    '\u{A9F0}',
+    '\u{AA50}',
+    '\u{ABF0}',
+    '\u{FF10}',
+    '\u{104A0}',
+    '\u{10D30}',
+    '\u{11066}',
+    '\u{110F0}',
+    '\u{11136}',
+    '\u{111D0}',
+    '\u{112F0}',
+    '\u{11450}',
+    '\u{114D0}',
+    '\u{11650}',
+    '\u{116C0}',
+    '\u{11730}',
+    '\u{118E0}',
+    '\u{11950}',
+    '\u{11C50}',
+    '\u{11D50}',
+    '\u{11DA0}',
+    '\u{16A60}',
+    '\u{16AC0}',
+    '\u{16B50}',
+    '\u{1D7CE}',
+    '\u{1D7D8}',
+    '\u{1D7E2}',
+    '\u{1D7EC}',
+    '\u{1D7F6}',
+    '\u{1E140}',
+    '\u{1E2F0}',
+    '\u{1E950}',
+    '\u{1FBF0}',
 ];
 
-const REVERSE_STRING_NUMBERS: &[&str] = &[
-    "eno", "owt", "eerht", "ruof", "evif", "xis", "neves", "thgie", "enin",
+// Number words from zero to nine, indexed by their value
+const ENGLISH_NUMBERS: &[&str] = &[
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
 ];
 
-fn get_leftmost_digit(mut chars: impl Iterator<Item = char>) -> usize {
-    chars
-        .find(|c| c.is_digit(10))
-        .unwrap_or_default()
-        .to_digit(10)
-        .unwrap() as usize
+const FRENCH_NUMBERS: &[&str] = &[
+    "zéro", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf",
+];
+
+const GERMAN_NUMBERS: &[&str] = &[
+    "null", "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+];
+
+const SPANISH_NUMBERS: &[&str] = &[
+    "cero", "uno", "dos", "tres", "cuatro", "cinco", "seis", "siete", "ocho", "nueve",
+];
+
+const TEEN_NUMBERS: &[&str] = &[
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+// Indexed by their value divided by ten, "zero" and "ten" are never used
+const TENS_NUMBERS: &[&str] = &[
+    "zero", "ten", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+const SCALE_NUMBERS: &[(&str, usize)] = &[
+    ("thousand", 1_000),
+    ("million", 1_000_000),
+    ("billion", 1_000_000_000),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    English,
+    French,
+    German,
+    Spanish,
+}
+
+impl Language {
+    fn numbers(self) -> &'static [&'static str] {
+        match self {
+            Language::English => ENGLISH_NUMBERS,
+            Language::French => FRENCH_NUMBERS,
+            Language::German => GERMAN_NUMBERS,
+            Language::Spanish => SPANISH_NUMBERS,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vocabulary {
+    words: Vec<(String, usize)>,
+    unicode_digits: bool,
+    case_insensitive: bool,
+}
+
+impl Vocabulary {
+    // Empty words are dropped, they would match at every position of a line.
+    // Words can stand for numbers above nine, a calibration value then takes
+    // the first digit of its first number and the last digit of its last one
+    pub fn new<S: Into<String>>(words: impl IntoIterator<Item = (S, usize)>) -> Self {
+        Self {
+            words: words
+                .into_iter()
+                .map(|(word, value)| (word.into(), value))
+                .filter(|(word, _)| !word.is_empty())
+                .collect(),
+            unicode_digits: false,
+            case_insensitive: false,
+        }
+    }
+
+    pub fn digits_only() -> Self {
+        Self::new::<String>([])
+    }
+
+    pub fn from_language(language: Language) -> Self {
+        Self::new(language.numbers().iter().copied().zip(0..).skip(1))
+    }
+
+    pub fn with_zero(language: Language) -> Self {
+        Self::new(language.numbers().iter().copied().zip(0..))
+    }
+
+    // One `word=value` pair per line, blank lines are ignored
+    pub fn parse(content: &str) -> io::Result<Self> {
+        let words = content
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| {
+                line.split_once('=')
+                    .and_then(|(word, value)| Some((word.trim(), value.trim().parse().ok()?)))
+                    .filter(|(word, _)| !word.is_empty())
+                    .ok_or_else(|| {
+                        io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            format!("invalid vocabulary entry on line {}: {line:?}", index + 1),
+                        )
+                    })
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        Ok(Self::new(words))
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::parse(&fs::read_to_string(path)?)
+    }
+
+    pub fn unicode_digits(mut self, enabled: bool) -> Self {
+        self.unicode_digits = enabled;
+        self
+    }
+
+    pub fn case_insensitive(mut self, enabled: bool) -> Self {
+        self.case_insensitive = enabled;
+        self
+    }
+
+    pub fn words(&self) -> impl Iterator<Item = (&str, usize)> {
+        self.words
+            .iter()
+            .map(|(word, value)| (word.as_str(), *value))
+    }
+}
+
+impl Default for Vocabulary {
+    fn default() -> Self {
+        Self::from_language(Language::English)
+    }
 }
 
-fn calculate_sum_leftmost_and_rightmost(line: &str) -> usize {
-    (get_leftmost_digit(line.chars()) * 10) + get_leftmost_digit(line.chars().rev())
+struct NumberScanner {
+    automaton: AhoCorasick,
+    values: Vec<usize>,
+    digit_patterns: usize,
+    case_insensitive: bool,
 }
 
-fn replace_first_leftmost_string_number(line: &str, string_numbers: &[&str]) -> String {
-    let mut a = vec![];
+impl NumberScanner {
+    fn new(vocabulary: &Vocabulary) -> Self {
+        let unicode_digits = UNICODE_ZERO_DIGITS
+            .iter()
+            .filter(|_| vocabulary.unicode_digits)
+            .flat_map(|&zero| {
+                (0..10).map(move |value| {
+                    let digit = char::from_u32(zero as u32 + value as u32).unwrap();
+                    (digit.to_string(), value)
+                })
+            });
 
-    let mut index = 0;
-    while index < line.len() {
-        let mut found = false;
-        for (i, &n) in string_numbers.iter().enumerate() {
-            if n.len() + index > line.len() {
-                continue;
+        let (mut patterns, mut values): (Vec<_>, Vec<_>) = DIGITS
+            .iter()
+            .map(|digit| digit.to_string())
+            .zip(0..)
+            .chain(unicode_digits)
+            .unzip();
+        let digit_patterns = patterns.len();
+
+        for (word, value) in vocabulary.words() {
+            patterns.push(if vocabulary.case_insensitive {
+                fold_case(word).0
+            } else {
+                word.to_string()
+            });
+            values.push(value);
+        }
+
+        Self {
+            automaton: AhoCorasick::new(patterns).unwrap(),
+            values,
+            digit_patterns,
+            case_insensitive: vocabulary.case_insensitive,
+        }
+    }
+
+    // Overlapping matches are needed for lines such as "eighthree" -> 83. When
+    // words nest (e.g. "eight" and "eighteen"), the longest one at a position wins
+    fn find_first_and_last_match(&self, line: &str) -> Option<(Match, Match)> {
+        if self.case_insensitive {
+            let (folded, spans) = fold_case(line);
+            let (first, last) = self.find_first_and_last_folded_match(&folded)?;
+            let unfold =
+                |m: Match| Match::new(m.pattern(), spans[m.start()].0..spans[m.end() - 1].1);
+
+            return Some((unfold(first), unfold(last)));
+        }
+
+        self.find_first_and_last_folded_match(line)
+    }
+
+    fn find_first_and_last_folded_match(&self, line: &str) -> Option<(Match, Match)> {
+        self.automaton
+            .find_overlapping_iter(line)
+            .fold(None, |acc, m| match acc {
+                None => Some((m, m)),
+                Some((first, last)) => Some((
+                    if (m.start(), first.len()) < (first.start(), m.len()) {
+                        m
+                    } else {
+                        first
+                    },
+                    if (m.start(), m.len()) > (last.start(), last.len()) {
+                        m
+                    } else {
+                        last
+                    },
+                )),
+            })
+    }
+
+    fn find_first_and_last_number(&self, line: &str) -> Option<(usize, usize)> {
+        let (first, last) = self.find_first_and_last_match(line)?;
+
+        Some((
+            self.values[first.pattern().as_usize()],
+            self.values[last.pattern().as_usize()],
+        ))
+    }
+
+    fn find_first_and_last_token(&self, line: &str) -> Option<(Token, Token)> {
+        let (first, last) = self.find_first_and_last_match(line)?;
+
+        Some((self.token(first), self.token(last)))
+    }
+
+    fn token(&self, m: Match) -> Token {
+        let pattern = m.pattern().as_usize();
+
+        Token {
+            span: m.range(),
+            kind: if pattern < self.digit_patterns {
+                TokenKind::Digit
+            } else {
+                TokenKind::Word
+            },
+            value: self.values[pattern],
+        }
+    }
+}
+
+// Lowercases the line, along with the byte span in the original line of the
+// character each lowercased byte comes from, as lowercasing can change lengths.
+// Characters are folded one at a time, so a final sigma is folded like any
+// other sigma rather than to `ς` as `str::to_lowercase` would
+fn fold_case(line: &str) -> (String, Vec<(usize, usize)>) {
+    let mut folded = String::with_capacity(line.len());
+    let mut spans = Vec::with_capacity(line.len());
+
+    for (start, c) in line.char_indices() {
+        let end = start + c.len_utf8();
+
+        for lowercase in c.to_lowercase() {
+            let lowercase = if lowercase == 'ς' { 'σ' } else { lowercase };
+            folded.push(lowercase);
+            spans.extend(iter::repeat_n((start, end), lowercase.len_utf8()));
+        }
+    }
+
+    (folded, spans)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    Digit,
+    Word,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    pub span: Range<usize>,
+    pub kind: TokenKind,
+    pub value: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MissingDigitPolicy {
+    #[default]
+    Error,
+    Skip,
+    Zero,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MissingDigitError {
+    pub line_number: usize,
+}
+
+impl fmt::Display for MissingDigitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no digit found on line {}", self.line_number)
+    }
+}
+
+impl Error for MissingDigitError {}
+
+// With `MissingDigitPolicy::Zero`, lines without any digit have no tokens
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineDiagnostic<'a> {
+    pub line_number: usize,
+    pub line: &'a str,
+    pub tokens: Option<(Token, Token)>,
+}
+
+impl LineDiagnostic<'_> {
+    pub fn calibration_value(&self) -> usize {
+        self.tokens
+            .as_ref()
+            .map_or(0, |(first, last)| join_digits(first.value, last.value))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JoinRule {
+    // 12 and 345 give 12345
+    Concatenate,
+    // 12 and 345 give 357
+    Sum,
+    // First digit of the first number and last digit of the last one, 12 and 345 give 15
+    Digits,
+}
+
+impl JoinRule {
+    // None if the joined value doesn't fit in a usize
+    pub fn join(self, first: usize, last: usize) -> Option<usize> {
+        match self {
+            JoinRule::Concatenate => {
+                let shift = 10usize.checked_pow(last.checked_ilog10().unwrap_or(0) + 1)?;
+                first.checked_mul(shift)?.checked_add(last)
             }
-            if n == &line[index..(n.len() + index)] {
-                a.push((i + 1).to_string());
-                index += n.len();
-                found = true;
-                break;
+            JoinRule::Sum => first.checked_add(last),
+            JoinRule::Digits => Some(join_digits(first, last)),
+        }
+    }
+
+    fn join_numbers(self, first: FullNumber, last: FullNumber) -> Option<usize> {
+        match self {
+            JoinRule::Digits => Some(first.first_digit * 10 + last.last_digit),
+            _ => self.join(first.value?, last.value?),
+        }
+    }
+}
+
+fn join_digits(first: usize, last: usize) -> usize {
+    let first_digit = first / 10usize.pow(first.checked_ilog10().unwrap_or(0));
+    first_digit * 10 + last % 10
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FullNumberError {
+    MissingDigit(MissingDigitError),
+    Overflow { line_number: usize },
+}
+
+impl fmt::Display for FullNumberError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FullNumberError::MissingDigit(error) => write!(f, "{error}"),
+            FullNumberError::Overflow { line_number } => {
+                write!(f, "calibration value on line {line_number} is too large")
             }
         }
-        if !found {
-            a.push(line[index..(index + 1)].to_string());
-            index += 1;
-        } else {
-            a.push(line[index..].to_string());
+    }
+}
+
+impl Error for FullNumberError {}
+
+// A number read from a line, its value is None when it doesn't fit in a usize.
+// Its first and last digits are always known, so `JoinRule::Digits` never fails
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct FullNumber {
+    value: Option<usize>,
+    first_digit: usize,
+    last_digit: usize,
+}
+
+impl FullNumber {
+    fn from_value(value: u64) -> Self {
+        Self {
+            value: usize::try_from(value).ok(),
+            first_digit: (value / 10u64.pow(value.checked_ilog10().unwrap_or(0))) as usize,
+            last_digit: (value % 10) as usize,
+        }
+    }
+
+    // `digits` is a non empty run of ASCII digits
+    fn from_digits(digits: &str) -> Self {
+        let bytes = digits.as_bytes();
+
+        Self {
+            value: digits.parse().ok(),
+            first_digit: usize::from(bytes[0] - b'0'),
+            last_digit: usize::from(bytes[bytes.len() - 1] - b'0'),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NumberWord {
+    Unit(usize),
+    Teen(usize),
+    Tens(usize),
+    Hundred,
+    Scale(usize),
+}
+
+fn find_number_word(s: &str) -> Option<(NumberWord, usize)> {
+    let units = ENGLISH_NUMBERS
+        .iter()
+        .zip(0..)
+        .map(|(w, v)| (*w, NumberWord::Unit(v)));
+    let teens = TEEN_NUMBERS
+        .iter()
+        .zip(10..)
+        .map(|(w, v)| (*w, NumberWord::Teen(v)));
+    let tens = TENS_NUMBERS
+        .iter()
+        .zip(0..)
+        .skip(2)
+        .map(|(w, v)| (*w, NumberWord::Tens(v * 10)));
+    let scales = SCALE_NUMBERS
+        .iter()
+        .map(|&(w, v)| (w, NumberWord::Scale(v)));
+
+    units
+        .chain(teens)
+        .chain(tens)
+        .chain([("hundred", NumberWord::Hundred)])
+        .chain(scales)
+        .filter(|(word, _)| s.starts_with(word))
+        .max_by_key(|(word, _)| word.len())
+        .map(|(word, number)| (number, word.len()))
+}
+
+// Greedily reads a number spelled out in English such as "twenty-one", "one
+// hundred" or "fivethousandtwelve", returning its value and byte length. Scales
+// only decrease, so the value is below a thousand billions and fits in a u64
+fn parse_number_words(s: &str) -> Option<(u64, usize)> {
+    let (mut total, mut current, mut scale) = (0u64, 0u64, u64::MAX);
+    let mut previous = None;
+    let mut end = 0;
+
+    loop {
+        let separator = usize::from(previous.is_some() && s[end..].starts_with([' ', '-']));
+        let Some((word, length)) = find_number_word(&s[end + separator..]) else {
             break;
+        };
+
+        match (previous, word) {
+            (None, NumberWord::Unit(value) | NumberWord::Teen(value) | NumberWord::Tens(value))
+            | (
+                Some(NumberWord::Hundred | NumberWord::Scale(_)),
+                NumberWord::Unit(value @ 1..) | NumberWord::Teen(value) | NumberWord::Tens(value),
+            )
+            | (Some(NumberWord::Tens(_)), NumberWord::Unit(value @ 1..)) => {
+                current += value as u64;
+            }
+            (Some(NumberWord::Unit(1..)), NumberWord::Hundred) if current < 10 => current *= 100,
+            (
+                Some(
+                    NumberWord::Unit(1..)
+                    | NumberWord::Teen(_)
+                    | NumberWord::Tens(_)
+                    | NumberWord::Hundred,
+                ),
+                NumberWord::Scale(value),
+            ) if (value as u64) < scale => {
+                total += current * value as u64;
+                current = 0;
+                scale = value as u64;
+            }
+            _ => break,
         }
+
+        previous = Some(word);
+        end += separator + length;
     }
 
-    a.join("")
+    previous.map(|_| (total + current, end))
 }
 
-fn reverse_string<'a>(s: impl Into<Cow<'a, str>>) -> String {
-    s.into().chars().rev().collect()
+fn parse_full_number(s: &str) -> Option<(FullNumber, usize)> {
+    let digits = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+
+    if digits > 0 {
+        Some((FullNumber::from_digits(&s[..digits]), digits))
+    } else {
+        let (value, length) = parse_number_words(s)?;
+        Some((FullNumber::from_value(value), length))
+    }
 }
 
-fn solver(file_content: &str, f: fn(&str) -> usize) -> usize {
-    file_content.split_whitespace().map(f).sum()
+// Numbers are read at every position so that overlapping ones are still found
+// ("eighthree" -> 8 and 3), but those inside a longer number that started
+// earlier are ignored ("twenty-one" is not followed by "one", "123" by "23").
+// Every number starting inside a run of digits is inside it, so the scan goes
+// on after the run rather than reading the rest of it again at each position
+fn find_first_and_last_full_number(line: &str) -> Option<(FullNumber, FullNumber)> {
+    let mut first_and_last = None;
+    let mut max_end = 0;
+    let mut start = 0;
+
+    while let Some(c) = line[start..].chars().next() {
+        match parse_full_number(&line[start..]) {
+            Some((number, length)) if start + length > max_end => {
+                max_end = start + length;
+                first_and_last = Some(match first_and_last {
+                    Some((first, _)) => (first, number),
+                    None => (number, number),
+                });
+            }
+            _ => {}
+        }
+
+        start = if c.is_ascii_digit() {
+            max_end
+        } else {
+            start + c.len_utf8()
+        };
+    }
+
+    first_and_last
+}
+
+fn get_leftmost_digit(mut chars: impl Iterator<Item = char>) -> Option<usize> {
+    chars
+        .find(|c| c.is_ascii_digit())
+        .and_then(|c| c.to_digit(10))
+        .map(|digit| digit as usize)
+}
+
+fn calculate_sum_leftmost_and_rightmost(line: &str) -> Option<usize> {
+    Some((get_leftmost_digit(line.chars())? * 10) + get_leftmost_digit(line.chars().rev())?)
+}
+
+const ONES: u64 = u64::from_ne_bytes([0x01; 8]);
+const HIGH_BITS: u64 = ONES * 0x80;
+
+// Sets the high bit of every byte of `chunk` that is an ASCII digit. The low
+// seven bits of each byte are offset so that their high bit tells whether they
+// are at least '0' and at least ':', which can never carry into the next byte
+fn digit_mask(chunk: u64) -> u64 {
+    let low_bits = chunk & !HIGH_BITS;
+    let at_least_zero = low_bits + ONES * (0x80 - b'0' as u64);
+    let past_nine = low_bits + ONES * (0x80 - b':' as u64);
+
+    at_least_zero & !past_nine & !chunk & HIGH_BITS
+}
+
+fn first_digit_byte(line: &[u8]) -> Option<u8> {
+    let mut chunks = line.chunks_exact(8);
+
+    for chunk in chunks.by_ref() {
+        let mask = digit_mask(u64::from_le_bytes(chunk.try_into().unwrap()));
+        if mask != 0 {
+            return Some(chunk[mask.trailing_zeros() as usize / 8] - b'0');
+        }
+    }
+
+    chunks
+        .remainder()
+        .iter()
+        .find(|b| b.is_ascii_digit())
+        .map(|b| b - b'0')
+}
+
+fn last_digit_byte(line: &[u8]) -> Option<u8> {
+    let mut chunks = line.rchunks_exact(8);
+
+    for chunk in chunks.by_ref() {
+        let mask = digit_mask(u64::from_le_bytes(chunk.try_into().unwrap()));
+        if mask != 0 {
+            return Some(chunk[7 - mask.leading_zeros() as usize / 8] - b'0');
+        }
+    }
+
+    chunks
+        .remainder()
+        .iter()
+        .rfind(|b| b.is_ascii_digit())
+        .map(|b| b - b'0')
+}
+
+fn calculate_sum_leftmost_and_rightmost_bytes(line: &[u8]) -> Option<usize> {
+    Some(first_digit_byte(line)? as usize * 10 + last_digit_byte(line)? as usize)
+}
+
+// Whitespace as defined by Unicode, in both the `&str` and the byte solvers
+fn is_blank(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+// Yields the line number, the line and what `f` found on it, or nothing if the
+// policy says to skip the line. Every line is one entry, even if it contains
+// spaces, so that errors and diagnostics point at input lines and spelled out
+// numbers such as "one hundred" stay whole. Blank lines only separate entries,
+// so they are always skipped whatever the policy
+fn calibrate<T>(
+    file_content: &str,
+    policy: MissingDigitPolicy,
+    f: impl Fn(&str) -> Option<T>,
+) -> impl Iterator<Item = Result<(usize, &str, Option<T>), MissingDigitError>> {
+    file_content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !is_blank(line))
+        .filter_map(move |(index, line)| match (f(line), policy) {
+            (Some(value), _) => Some(Ok((index + 1, line, Some(value)))),
+            (None, MissingDigitPolicy::Error) => Some(Err(MissingDigitError {
+                line_number: index + 1,
+            })),
+            (None, MissingDigitPolicy::Skip) => None,
+            (None, MissingDigitPolicy::Zero) => Some(Ok((index + 1, line, None))),
+        })
+}
+
+fn solver(
+    file_content: &str,
+    policy: MissingDigitPolicy,
+    f: impl Fn(&str) -> Option<usize>,
+) -> Result<usize, MissingDigitError> {
+    calibrate(file_content, policy, f)
+        .map(|result| result.map(|(_, _, value)| value.unwrap_or_default()))
+        .sum()
+}
+
+pub fn solve_part_1_with_policy(
+    file_content: &str,
+    policy: MissingDigitPolicy,
+) -> Result<usize, MissingDigitError> {
+    solver(file_content, policy, calculate_sum_leftmost_and_rightmost)
 }
 
 pub fn solve_part_1(file_content: &str) -> usize {
-    solver(file_content, calculate_sum_leftmost_and_rightmost)
+    solve_part_1_with_policy(file_content, MissingDigitPolicy::default())
+        .unwrap_or_else(|error| panic!("{error}"))
+}
+
+// Same as part 1 but works on raw bytes, for inputs too large to be checked as
+// UTF-8 or split into `&str` lines
+pub fn solve_part_1_bytes(
+    file_content: &[u8],
+    policy: MissingDigitPolicy,
+) -> Result<usize, MissingDigitError> {
+    let mut start = 0;
+    let mut sum = 0;
+
+    let line_ends = memchr::memchr_iter(b'\n', file_content).chain(iter::once(file_content.len()));
+    for (index, end) in line_ends.enumerate() {
+        if start == file_content.len() {
+            break;
+        }
+
+        let line = &file_content[start..end];
+        start = end + 1;
+
+        match (calculate_sum_leftmost_and_rightmost_bytes(line), policy) {
+            (Some(value), _) => sum += value,
+            // Only lines without digits need to be decoded to tell if they are blank
+            (None, _) if str::from_utf8(line).is_ok_and(is_blank) => {}
+            (None, MissingDigitPolicy::Error) => {
+                return Err(MissingDigitError {
+                    line_number: index + 1,
+                })
+            }
+            (None, MissingDigitPolicy::Skip | MissingDigitPolicy::Zero) => {}
+        }
+    }
+
+    Ok(sum)
+}
+
+pub fn solve_with_vocabulary(
+    file_content: &str,
+    vocabulary: &Vocabulary,
+    policy: MissingDigitPolicy,
+) -> Result<usize, MissingDigitError> {
+    let scanner = NumberScanner::new(vocabulary);
+
+    solver(file_content, policy, |s| {
+        let (first, last) = scanner.find_first_and_last_number(s)?;
+
+        Some(join_digits(first, last))
+    })
 }
 
 pub fn solve_part_2(file_content: &str) -> usize {
-    solver(file_content, |s| {
-        let first_part = replace_first_leftmost_string_number(s, STRING_NUMBERS);
+    solve_with_vocabulary(
+        file_content,
+        &Vocabulary::default(),
+        MissingDigitPolicy::default(),
+    )
+    .unwrap_or_else(|error| panic!("{error}"))
+}
+
+pub fn solve_full_numbers(
+    file_content: &str,
+    join_rule: JoinRule,
+    policy: MissingDigitPolicy,
+) -> Result<usize, FullNumberError> {
+    let mut values = calibrate(file_content, policy, |s| {
+        let (first, last) = find_first_and_last_full_number(s)?;
+        Some(join_rule.join_numbers(first, last))
+    });
+
+    values.try_fold(0usize, |sum, result| {
+        let (line_number, _, value) = result.map_err(FullNumberError::MissingDigit)?;
+        let overflow = FullNumberError::Overflow { line_number };
+
+        let value = match value {
+            Some(value) => value.ok_or(overflow)?,
+            None => 0,
+        };
+        sum.checked_add(value).ok_or(overflow)
+    })
+}
 
-        let reversed_s = reverse_string(s);
-        let b = replace_first_leftmost_string_number(&reversed_s, REVERSE_STRING_NUMBERS);
-        let second_part = reverse_string(b);
+pub fn diagnose_with_vocabulary<'a>(
+    file_content: &'a str,
+    vocabulary: &Vocabulary,
+    policy: MissingDigitPolicy,
+) -> Result<Vec<LineDiagnostic<'a>>, MissingDigitError> {
+    let scanner = NumberScanner::new(vocabulary);
 
-        calculate_sum_leftmost_and_rightmost(&format!("{first_part}{second_part}"))
+    calibrate(file_content, policy, |line| {
+        scanner.find_first_and_last_token(line)
     })
+    .map(|result| {
+        result.map(|(line_number, line, tokens)| LineDiagnostic {
+            line_number,
+            line,
+            tokens,
+        })
+    })
+    .collect()
+}
+
+pub fn diagnose_part_1(
+    file_content: &str,
+    policy: MissingDigitPolicy,
+) -> Result<Vec<LineDiagnostic<'_>>, MissingDigitError> {
+    diagnose_with_vocabulary(file_content, &Vocabulary::digits_only(), policy)
+}
+
+pub fn diagnose_part_2(
+    file_content: &str,
+    policy: MissingDigitPolicy,
+) -> Result<Vec<LineDiagnostic<'_>>, MissingDigitError> {
+    diagnose_with_vocabulary(file_content, &Vocabulary::default(), policy)
 }
 
 #[cfg(test)]
@@ -95,31 +858,159 @@ sevenine"#;
     #[test]
     fn test_get_leftmost_digit() {
         let result = get_leftmost_digit("1abc2".chars());
-        assert_eq!(result, 1);
+        assert_eq!(result, Some(1));
 
         let result = get_leftmost_digit("pqr3stu8vwx".chars());
-        assert_eq!(result, 3);
+        assert_eq!(result, Some(3));
 
         let result = get_leftmost_digit("a1b2c3d4e5f".chars());
-        assert_eq!(result, 1);
+        assert_eq!(result, Some(1));
 
         let result = get_leftmost_digit("treb7uchet".chars());
-        assert_eq!(result, 7);
+        assert_eq!(result, Some(7));
     }
 
     #[test]
     fn test_calculate_sum_leftmost_and_rightmost() {
         let result = calculate_sum_leftmost_and_rightmost("1abc2");
-        assert_eq!(result, 12);
+        assert_eq!(result, Some(12));
 
         let result = calculate_sum_leftmost_and_rightmost("pqr3stu8vwx");
-        assert_eq!(result, 38);
+        assert_eq!(result, Some(38));
 
         let result = calculate_sum_leftmost_and_rightmost("a1b2c3d4e5f");
-        assert_eq!(result, 15);
+        assert_eq!(result, Some(15));
 
         let result = calculate_sum_leftmost_and_rightmost("treb7uchet");
-        assert_eq!(result, 77);
+        assert_eq!(result, Some(77));
+    }
+
+    #[test]
+    fn test_find_first_and_last_number() {
+        let scanner = NumberScanner::new(&Vocabulary::default());
+
+        let result = scanner.find_first_and_last_number("two1nine");
+        assert_eq!(result, Some((2, 9)));
+
+        let result = scanner.find_first_and_last_number("eighthree");
+        assert_eq!(result, Some((8, 3)));
+
+        let result = scanner.find_first_and_last_number("xtwone3four");
+        assert_eq!(result, Some((2, 4)));
+
+        let result = scanner.find_first_and_last_number("4nineeightseven2");
+        assert_eq!(result, Some((4, 2)));
+
+        let result = scanner.find_first_and_last_number("7pqrstsixteen");
+        assert_eq!(result, Some((7, 6)));
+
+        let result = scanner.find_first_and_last_number("zoneight");
+        assert_eq!(result, Some((1, 8)));
+    }
+
+    // Small xorshift generator, so that the lines are the same on every run
+    fn generate_lines(count: usize) -> Vec<String> {
+        const ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789\r\t :/;\x7f";
+        const MULTI_BYTE: &[&str] = &["é", "٣", "７", "𝟘"];
+
+        let mut state = 0x2545f4914f6cdd1du64;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as usize
+        };
+
+        (0..count)
+            .map(|_| {
+                let length = next() % 40;
+                (0..length)
+                    .map(|_| match next() % 8 {
+                        0 => MULTI_BYTE[next() % MULTI_BYTE.len()].to_string(),
+                        _ => (ALPHABET[next() % ALPHABET.len()] as char).to_string(),
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_digit_mask() {
+        let result = digit_mask(u64::from_le_bytes(*b"a0b9/:\xb0\xb9"));
+        assert_eq!(result, u64::from_le_bytes([0, 0x80, 0, 0x80, 0, 0, 0, 0]));
+    }
+
+    #[test]
+    fn test_calculate_sum_leftmost_and_rightmost_bytes() {
+        for line in generate_lines(2000) {
+            let result = calculate_sum_leftmost_and_rightmost_bytes(line.as_bytes());
+            assert_eq!(
+                result,
+                calculate_sum_leftmost_and_rightmost(&line),
+                "{line:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_solve_part_1_bytes() {
+        let result = solve_part_1_bytes(SAMPLE_DATA_1.as_bytes(), MissingDigitPolicy::Error);
+        assert_eq!(result, Ok(142));
+
+        let result = solve_part_1_bytes(b"1abc2\n\ntreb7uchet\n", MissingDigitPolicy::Error);
+        assert_eq!(result, Ok(12 + 77));
+
+        let result = solve_part_1_bytes(b"1abc2\n \nabc\n", MissingDigitPolicy::Error);
+        assert_eq!(result, Err(MissingDigitError { line_number: 3 }));
+
+        let result = solve_part_1_bytes(b"1abc2\nabc\ntreb7uchet\n", MissingDigitPolicy::Skip);
+        assert_eq!(result, Ok(12 + 77));
+
+        let result = solve_part_1_bytes(b"", MissingDigitPolicy::Error);
+        assert_eq!(result, Ok(0));
+
+        let result = solve_part_1_bytes(b"12\n\x0b\n34\n\xc2\xa0", MissingDigitPolicy::Error);
+        assert_eq!(result, Ok(12 + 34));
+
+        let result = solve_part_1_bytes(b"12\n\xa0\n34", MissingDigitPolicy::Error);
+        assert_eq!(result, Err(MissingDigitError { line_number: 2 }));
+    }
+
+    #[test]
+    fn test_solve_part_1_bytes_same_as_str() {
+        const BLANK_LINES: &[&str] = &["", " \t", "\x0b", "\x0c", "\u{a0}", "\u{3000}\r"];
+
+        let lines = generate_lines(2000)
+            .into_iter()
+            .enumerate()
+            .flat_map(|(index, line)| {
+                let blank = (index % 3 == 0).then(|| BLANK_LINES[index % BLANK_LINES.len()]);
+                [line].into_iter().chain(blank.map(str::to_string))
+            })
+            .collect::<Vec<_>>();
+
+        let with_digits = lines
+            .iter()
+            .filter(|line| line.bytes().any(|b| b.is_ascii_digit()) || is_blank(line))
+            .cloned()
+            .collect::<Vec<_>>()
+            .join("\n");
+        let all = lines.join("\n");
+
+        for (lines, policy) in [
+            (&with_digits, MissingDigitPolicy::Error),
+            (&all, MissingDigitPolicy::Error),
+            (&all, MissingDigitPolicy::Skip),
+            (&all, MissingDigitPolicy::Zero),
+        ] {
+            let result = solve_part_1_bytes(lines.as_bytes(), policy);
+            assert_eq!(
+                result,
+                solve_part_1_with_policy(lines, policy),
+                "{policy:?}"
+            );
+        }
+        assert!(solve_part_1_bytes(with_digits.as_bytes(), MissingDigitPolicy::Error).is_ok());
     }
 
     #[test]
@@ -133,4 +1024,496 @@ sevenine"#;
         let result = solve_part_2(SAMPLE_DATA_2);
         assert_eq!(result, 281 + 83 + 79);
     }
+
+    #[test]
+    fn test_diagnose_part_2() {
+        let result = diagnose_part_2(
+            "two1nine\n\nxtwone3four\n7pqrstsixteen",
+            MissingDigitPolicy::Skip,
+        )
+        .unwrap();
+        assert_eq!(
+            result,
+            vec![
+                LineDiagnostic {
+                    line_number: 1,
+                    line: "two1nine",
+                    tokens: Some((
+                        Token {
+                            span: 0..3,
+                            kind: TokenKind::Word,
+                            value: 2
+                        },
+                        Token {
+                            span: 4..8,
+                            kind: TokenKind::Word,
+                            value: 9
+                        }
+                    )),
+                },
+                LineDiagnostic {
+                    line_number: 3,
+                    line: "xtwone3four",
+                    tokens: Some((
+                        Token {
+                            span: 1..4,
+                            kind: TokenKind::Word,
+                            value: 2
+                        },
+                        Token {
+                            span: 7..11,
+                            kind: TokenKind::Word,
+                            value: 4
+                        }
+                    )),
+                },
+                LineDiagnostic {
+                    line_number: 4,
+                    line: "7pqrstsixteen",
+                    tokens: Some((
+                        Token {
+                            span: 0..1,
+                            kind: TokenKind::Digit,
+                            value: 7
+                        },
+                        Token {
+                            span: 6..9,
+                            kind: TokenKind::Word,
+                            value: 6
+                        }
+                    )),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diagnose_part_1() {
+        let result = diagnose_part_1(SAMPLE_DATA_1, MissingDigitPolicy::Error).unwrap();
+        let (first, last) = result[3].tokens.clone().unwrap();
+        assert_eq!(first, last);
+        assert_eq!(first.span, 4..5);
+
+        let result = result
+            .iter()
+            .map(LineDiagnostic::calibration_value)
+            .collect::<Vec<_>>();
+        assert_eq!(result, vec![12, 38, 15, 77]);
+    }
+
+    #[test]
+    fn test_missing_digit_policy_part_1() {
+        const DATA: &str = "1abc2\n\nabc\ntreb7uchet";
+
+        let result = solve_part_1_with_policy(DATA, MissingDigitPolicy::Error);
+        assert_eq!(result, Err(MissingDigitError { line_number: 3 }));
+
+        let result = solve_part_1_with_policy("1abc2\nabc", MissingDigitPolicy::Error);
+        assert_eq!(result, Err(MissingDigitError { line_number: 2 }));
+
+        let result = solve_part_1_with_policy(DATA, MissingDigitPolicy::Skip);
+        assert_eq!(result, Ok(12 + 77));
+
+        let result = solve_part_1_with_policy(DATA, MissingDigitPolicy::Zero);
+        assert_eq!(result, Ok(12 + 77));
+
+        let result = diagnose_part_1(DATA, MissingDigitPolicy::Skip).unwrap();
+        assert_eq!(result.len(), 2);
+
+        let result = diagnose_part_1(DATA, MissingDigitPolicy::Zero).unwrap();
+        assert_eq!(result.len(), 3);
+        assert_eq!(result[1].line_number, 3);
+        assert_eq!(result[1].tokens, None);
+        assert_eq!(result[1].calibration_value(), 0);
+    }
+
+    #[test]
+    fn test_missing_digit_policy_part_2() {
+        const DATA: &str = "two1nine\nabc\n\neighthree";
+        let vocabulary = Vocabulary::default();
+
+        let result = solve_with_vocabulary(DATA, &vocabulary, MissingDigitPolicy::Error);
+        assert_eq!(result, Err(MissingDigitError { line_number: 2 }));
+
+        let result =
+            solve_with_vocabulary("two1nine\n\nabc", &vocabulary, MissingDigitPolicy::Error);
+        assert_eq!(result, Err(MissingDigitError { line_number: 3 }));
+
+        let result = solve_with_vocabulary(DATA, &vocabulary, MissingDigitPolicy::Skip);
+        assert_eq!(result, Ok(29 + 83));
+
+        let result = solve_with_vocabulary(DATA, &vocabulary, MissingDigitPolicy::Zero);
+        assert_eq!(result, Ok(29 + 83));
+
+        let result = diagnose_part_2(DATA, MissingDigitPolicy::Error);
+        assert_eq!(result, Err(MissingDigitError { line_number: 2 }));
+
+        let result = diagnose_part_2(DATA, MissingDigitPolicy::Zero).unwrap();
+        assert_eq!(
+            result.iter().map(|d| d.line_number).collect::<Vec<_>>(),
+            vec![1, 2, 4]
+        );
+    }
+
+    #[test]
+    fn test_blank_lines_are_skipped() {
+        assert_eq!(solve_part_1("1abc2\n\ntreb7uchet\n"), 12 + 77);
+        assert_eq!(solve_part_1("\n1abc2\n   \ntreb7uchet\n\n"), 12 + 77);
+        assert_eq!(solve_part_2("two1nine\n\neighthree\n"), 29 + 83);
+        assert_eq!(solve_part_2(""), 0);
+    }
+
+    #[test]
+    fn test_entries_are_lines() {
+        assert_eq!(solve_part_1("1abc2 treb7uchet"), 17);
+        assert_eq!(
+            solve_part_1_bytes(b"1abc2 treb7uchet", MissingDigitPolicy::Error),
+            Ok(17)
+        );
+        assert_eq!(solve_part_2("two1 nine\n3 x"), 29 + 33);
+
+        let result = diagnose_part_1("1abc2 treb7uchet\nab 4", MissingDigitPolicy::Error).unwrap();
+        assert_eq!(
+            result
+                .iter()
+                .map(|diagnostic| (diagnostic.line_number, diagnostic.line))
+                .collect::<Vec<_>>(),
+            vec![(1, "1abc2 treb7uchet"), (2, "ab 4")]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "no digit found on line 3")]
+    fn test_solve_part_1_without_digit() {
+        solve_part_1("1abc2\n\nabc");
+    }
+
+    #[test]
+    #[should_panic(expected = "no digit found on line 2")]
+    fn test_solve_part_2_without_digit() {
+        solve_part_2("two1nine\nabc");
+    }
+
+    #[test]
+    fn test_fold_case() {
+        let (folded, spans) = fold_case("xFÜ");
+        assert_eq!(folded, "xfü");
+        assert_eq!(spans, vec![(0, 1), (1, 2), (2, 4), (2, 4)]);
+
+        let (folded, spans) = fold_case("İ1");
+        assert_eq!(folded, "i\u{307}1");
+        assert_eq!(spans, vec![(0, 2), (0, 2), (0, 2), (2, 3)]);
+    }
+
+    #[test]
+    fn test_case_insensitive_spans() {
+        let vocabulary = Vocabulary::from_language(Language::German).case_insensitive(true);
+        let scanner = NumberScanner::new(&vocabulary);
+
+        let (first, last) = scanner.find_first_and_last_token("İxFÜNFzwEI").unwrap();
+        assert_eq!((first.span, first.value), (3..8, 5));
+        assert_eq!((last.span, last.value), (8..12, 2));
+    }
+
+    #[test]
+    fn test_case_insensitive_long_words() {
+        let word = "ДВЕНАДЦАТЬЮ";
+        let vocabulary = Vocabulary::new([(word, 12)]).case_insensitive(true);
+        let scanner = NumberScanner::new(&vocabulary);
+        assert_eq!(scanner.automaton.patterns_len(), DIGITS.len() + 1);
+
+        let result = scanner.find_first_and_last_number("xдвенадцатьЮ3");
+        assert_eq!(result, Some((12, 3)));
+    }
+
+    #[test]
+    fn test_case_insensitive_final_sigma() {
+        let vocabulary = Vocabulary::new([("ΣΑΣ", 4)]).case_insensitive(true);
+        let result = solve_with_vocabulary("ΣΑΣ\nσας\nσασ", &vocabulary, MissingDigitPolicy::Error);
+        assert_eq!(result, Ok(3 * 44));
+
+        let vocabulary = Vocabulary::new([("σας", 4)]).case_insensitive(true);
+        let result = solve_with_vocabulary("ΣΑΣ", &vocabulary, MissingDigitPolicy::Error);
+        assert_eq!(result, Ok(44));
+    }
+
+    #[test]
+    fn test_unicode_digits() {
+        let vocabulary = Vocabulary::digits_only().unicode_digits(true);
+        let scanner = NumberScanner::new(&vocabulary);
+
+        let result = scanner.find_first_and_last_token("a٣bc７é");
+        assert_eq!(
+            result,
+            Some((
+                Token {
+                    span: 1..3,
+                    kind: TokenKind::Digit,
+                    value: 3
+                },
+                Token {
+                    span: 5..8,
+                    kind: TokenKind::Digit,
+                    value: 7
+                }
+            ))
+        );
+
+        let result = solve_with_vocabulary("१x२\né4\n𝟘𝟡", &vocabulary, MissingDigitPolicy::Error);
+        assert_eq!(result, Ok(12 + 44 + 9));
+
+        let result =
+            solve_with_vocabulary("١٢", &Vocabulary::digits_only(), MissingDigitPolicy::Error);
+        assert_eq!(result, Err(MissingDigitError { line_number: 1 }));
+    }
+
+    #[test]
+    fn test_case_insensitive_words() {
+        let vocabulary = Vocabulary::default().case_insensitive(true);
+        let result = solve_with_vocabulary(
+            "Two1NINE\neIGHThree\nxtwOne",
+            &vocabulary,
+            MissingDigitPolicy::Error,
+        );
+        assert_eq!(result, Ok(29 + 83 + 21));
+
+        let vocabulary = Vocabulary::from_language(Language::German).case_insensitive(true);
+        let result =
+            solve_with_vocabulary("FÜNFx\nÄfünfzWEI", &vocabulary, MissingDigitPolicy::Error);
+        assert_eq!(result, Ok(55 + 52));
+
+        let result = solve_with_vocabulary(
+            "Two1NINE",
+            &Vocabulary::default(),
+            MissingDigitPolicy::Error,
+        );
+        assert_eq!(result, Ok(11));
+    }
+
+    #[test]
+    fn test_parse_number_words() {
+        assert_eq!(parse_number_words("seven"), Some((7, 5)));
+        assert_eq!(parse_number_words("seventeenx"), Some((17, 9)));
+        assert_eq!(parse_number_words("twenty-one"), Some((21, 10)));
+        assert_eq!(parse_number_words("twentyone"), Some((21, 9)));
+        assert_eq!(parse_number_words("one hundred"), Some((100, 11)));
+        assert_eq!(parse_number_words("one hundred-"), Some((100, 11)));
+        assert_eq!(parse_number_words("ninehundredninetynine"), Some((999, 21)));
+        assert_eq!(
+            parse_number_words("two thousand three hundred forty-five"),
+            Some((2345, 37))
+        );
+        assert_eq!(
+            parse_number_words("one hundred thousand"),
+            Some((100_000, 20))
+        );
+        assert_eq!(parse_number_words("onetwo"), Some((1, 3)));
+        assert_eq!(parse_number_words("twentyten"), Some((20, 6)));
+        assert_eq!(parse_number_words("zeroone"), Some((0, 4)));
+        assert_eq!(parse_number_words("twenty--one"), Some((20, 6)));
+        assert_eq!(parse_number_words("hundred"), None);
+        assert_eq!(parse_number_words("thousand"), None);
+        assert_eq!(parse_number_words("abc"), None);
+    }
+
+    #[test]
+    fn test_find_first_and_last_full_number() {
+        let values = |line| {
+            find_first_and_last_full_number(line).map(|(first, last)| (first.value, last.value))
+        };
+
+        assert_eq!(values("ab123cd45"), Some((Some(123), Some(45))));
+        assert_eq!(values("x12twenty-one"), Some((Some(12), Some(21))));
+        assert_eq!(values("one hundred"), Some((Some(100), Some(100))));
+        assert_eq!(values("eighthree"), Some((Some(8), Some(3))));
+        assert_eq!(values("nineteen"), Some((Some(19), Some(19))));
+        assert_eq!(values("12seven3"), Some((Some(12), Some(3))));
+        assert_eq!(values("abc"), None);
+
+        let result = find_first_and_last_full_number("a123456789012345678901234b");
+        let number = FullNumber {
+            value: None,
+            first_digit: 1,
+            last_digit: 4,
+        };
+        assert_eq!(result, Some((number, number)));
+
+        let result = find_first_and_last_full_number("ninety-one billion");
+        let number = FullNumber {
+            value: usize::try_from(91_000_000_000u64).ok(),
+            first_digit: 9,
+            last_digit: 0,
+        };
+        assert_eq!(result, Some((number, number)));
+    }
+
+    #[test]
+    fn test_join_rule() {
+        assert_eq!(JoinRule::Concatenate.join(12, 345), Some(12345));
+        assert_eq!(JoinRule::Sum.join(12, 345), Some(357));
+        assert_eq!(JoinRule::Digits.join(12, 345), Some(15));
+        assert_eq!(JoinRule::Digits.join(0, 7), Some(7));
+        assert_eq!(JoinRule::Concatenate.join(12, 0), Some(120));
+        assert_eq!(JoinRule::Concatenate.join(usize::MAX / 10 + 1, 1), None);
+        assert_eq!(JoinRule::Sum.join(usize::MAX, 1), None);
+    }
+
+    #[test]
+    fn test_solve_full_numbers() {
+        const DATA: &str = "12abc345\ntwenty-one7\nxone hundredx\n\nsevenine";
+
+        let result = solve_full_numbers(DATA, JoinRule::Concatenate, MissingDigitPolicy::Skip);
+        assert_eq!(result, Ok(12345 + 217 + 100100 + 79));
+
+        let result = solve_full_numbers(DATA, JoinRule::Sum, MissingDigitPolicy::Skip);
+        assert_eq!(result, Ok(357 + 28 + 200 + 16));
+
+        let result = solve_full_numbers(DATA, JoinRule::Digits, MissingDigitPolicy::Skip);
+        assert_eq!(result, Ok(15 + 27 + 10 + 79));
+
+        let result = solve_full_numbers(DATA, JoinRule::Digits, MissingDigitPolicy::Error);
+        assert_eq!(result, Ok(15 + 27 + 10 + 79));
+
+        let result = solve_full_numbers("12\nabc", JoinRule::Digits, MissingDigitPolicy::Error);
+        assert_eq!(
+            result,
+            Err(FullNumberError::MissingDigit(MissingDigitError {
+                line_number: 2
+            }))
+        );
+
+        let result = solve_full_numbers(
+            "1\na123456789012345678901234b",
+            JoinRule::Sum,
+            MissingDigitPolicy::Error,
+        );
+        assert_eq!(result, Err(FullNumberError::Overflow { line_number: 2 }));
+
+        let result = solve_full_numbers(
+            "a123456789012345678901234b",
+            JoinRule::Digits,
+            MissingDigitPolicy::Error,
+        );
+        assert_eq!(result, Ok(14));
+
+        let line = format!("x{}y", "1".repeat(200_000));
+        let result = solve_full_numbers(&line, JoinRule::Digits, MissingDigitPolicy::Error);
+        assert_eq!(result, Ok(11));
+
+        let result = solve_full_numbers(&line, JoinRule::Sum, MissingDigitPolicy::Error);
+        assert_eq!(result, Err(FullNumberError::Overflow { line_number: 1 }));
+
+        let result = solve_full_numbers(
+            "9999999999 x 9999999999",
+            JoinRule::Concatenate,
+            MissingDigitPolicy::Error,
+        );
+        assert_eq!(result, Err(FullNumberError::Overflow { line_number: 1 }));
+
+        let result = solve_full_numbers(
+            "9999999999 x 9999999999",
+            JoinRule::Digits,
+            MissingDigitPolicy::Error,
+        );
+        assert_eq!(result, Ok(99));
+
+        let line = format!("{}\n{}", usize::MAX - 1, usize::MAX - 1);
+        let result = solve_full_numbers(&line, JoinRule::Sum, MissingDigitPolicy::Error);
+        assert_eq!(result, Err(FullNumberError::Overflow { line_number: 1 }));
+
+        let result = solve_full_numbers(&line, JoinRule::Digits, MissingDigitPolicy::Error);
+        assert_eq!(result, Ok(2 * 14));
+
+        let result = solve_full_numbers(SAMPLE_DATA_2, JoinRule::Digits, MissingDigitPolicy::Error);
+        assert_eq!(result, Ok(solve_part_2(SAMPLE_DATA_2)));
+    }
+
+    #[test]
+    fn test_vocabulary_with_nested_words() {
+        let vocabulary = Vocabulary::new([("eight", 8), ("eighteen", 18)]);
+        let scanner = NumberScanner::new(&vocabulary);
+
+        let result = scanner.find_first_and_last_number("eighteen");
+        assert_eq!(result, Some((18, 18)));
+
+        let result = scanner.find_first_and_last_number("x5eightx");
+        assert_eq!(result, Some((5, 8)));
+
+        let result = solve_with_vocabulary(
+            "eighteen\n3eighteen",
+            &vocabulary,
+            MissingDigitPolicy::Error,
+        );
+        assert_eq!(result, Ok(18 + 38));
+
+        let result = diagnose_with_vocabulary("eighteen", &vocabulary, MissingDigitPolicy::Error);
+        assert_eq!(result.unwrap()[0].calibration_value(), 18);
+    }
+
+    #[test]
+    fn test_vocabulary_without_empty_words() {
+        let vocabulary = Vocabulary::new([("", 1), ("one", 1)]);
+        assert_eq!(vocabulary, Vocabulary::new([("one", 1)]));
+
+        let result = solve_with_vocabulary("x", &vocabulary, MissingDigitPolicy::Error);
+        assert_eq!(result, Err(MissingDigitError { line_number: 1 }));
+    }
+
+    #[test]
+    fn test_vocabulary_parse() {
+        let result = Vocabulary::parse("un = 1\n\ndeux=2\n").unwrap();
+        assert_eq!(result, Vocabulary::new([("un", 1), ("deux", 2)]));
+
+        assert!(Vocabulary::parse("un=1\ndeux").is_err());
+        assert!(Vocabulary::parse("=1").is_err());
+        assert!(Vocabulary::parse("un=one").is_err());
+
+        let vocabulary = Vocabulary::parse(&format!("big={}", usize::MAX)).unwrap();
+        let result = solve_with_vocabulary("xbig\n0big", &vocabulary, MissingDigitPolicy::Error);
+        assert_eq!(result, Ok(15 + 5));
+    }
+
+    #[test]
+    fn test_solve_with_vocabulary() {
+        let vocabulary = Vocabulary::from_language(Language::French);
+        assert_eq!(
+            solve_with_vocabulary(
+                "troisx7\nhuitrois\nsix",
+                &vocabulary,
+                MissingDigitPolicy::Error
+            )
+            .unwrap(),
+            37 + 83 + 66
+        );
+
+        let vocabulary = Vocabulary::from_language(Language::German);
+        assert_eq!(
+            solve_with_vocabulary("fünfzwei\nachtneun", &vocabulary, MissingDigitPolicy::Error)
+                .unwrap(),
+            52 + 89
+        );
+
+        let vocabulary = Vocabulary::from_language(Language::Spanish);
+        assert_eq!(
+            solve_with_vocabulary(
+                "cuatro1cinco\nsiete",
+                &vocabulary,
+                MissingDigitPolicy::Error
+            )
+            .unwrap(),
+            45 + 77
+        );
+
+        let vocabulary = Vocabulary::with_zero(Language::English);
+        assert_eq!(
+            solve_with_vocabulary(
+                "zeroneight\nthreezero",
+                &vocabulary,
+                MissingDigitPolicy::Error
+            )
+            .unwrap(),
+            8 + 30
+        );
+        assert_eq!(solve_part_2("threezero"), 33);
+    }
 }
@@ -1,7 +1,14 @@
-use day2::solve_part_1;
+use day2::{solve_part_1_with_bag, Bag};
 
 fn main() {
-    let answer = solve_part_1(include_str!("../../input"));
+    let mut args = std::env::args().skip(1);
+    let bag = match (args.next().as_deref(), args.next()) {
+        (None, _) => Bag::default(),
+        (Some("--bag"), Some(bag)) => bag.parse().unwrap_or_else(|error| panic!("{error}")),
+        _ => panic!("usage: part_1 [--bag red=12,green=13,blue=14]"),
+    };
+
+    let answer = solve_part_1_with_bag(include_str!("../../input"), &bag);
 
     println!("Answer part 1: {answer}");
 }
@@ -0,0 +1,189 @@
+use day2::{
+    colors, draw_distribution, impossible_rounds, minimal_bags, overall_minimal_bag, parse_games,
+    possible_games, Bag, Game,
+};
+use std::fs;
+
+const USAGE: &str = "usage: query <possible|minimal|distribution|impossible> \
+    [--bag red=12,green=13,blue=14]... [--input PATH]";
+
+fn print_table(header: Vec<String>, rows: Vec<Vec<String>>) {
+    let widths = header
+        .iter()
+        .enumerate()
+        .map(|(index, title)| {
+            rows.iter()
+                .map(|row| row[index].chars().count())
+                .chain([title.chars().count()])
+                .max()
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    let format_row = |row: &[String]| {
+        row.iter()
+            .zip(&widths)
+            .map(|(cell, width)| {
+                let padding = width - cell.chars().count();
+                format!("{}{cell}", " ".repeat(padding))
+            })
+            .collect::<Vec<_>>()
+            .join(" | ")
+    };
+
+    println!("{}", format_row(&header));
+    println!(
+        "{}",
+        widths
+            .iter()
+            .map(|width| "-".repeat(*width))
+            .collect::<Vec<_>>()
+            .join("-+-")
+    );
+    for row in rows {
+        println!("{}", format_row(&row));
+    }
+}
+
+// Bags given with --bag, and the input file given with --input if any
+fn parse_options(mut args: impl Iterator<Item = String>) -> (Vec<Bag>, Option<String>) {
+    let mut bags = vec![];
+    let mut input = None;
+
+    while let Some(arg) = args.next() {
+        match (arg.as_str(), args.next()) {
+            ("--bag", Some(bag)) => {
+                bags.push(bag.parse().unwrap_or_else(|error| panic!("{error}")))
+            }
+            ("--input", Some(path)) if input.is_none() => input = Some(path),
+            _ => panic!("{USAGE}"),
+        }
+    }
+
+    (bags, input)
+}
+
+fn possible(games: &[Game], bags: &[Bag]) {
+    let header = ["game".to_string()]
+        .into_iter()
+        .chain(bags.iter().map(Bag::to_string))
+        .collect();
+
+    let mut id_sums = vec![0; bags.len()];
+    let mut rows = possible_games(games, bags)
+        .into_iter()
+        .map(|(id, possible)| {
+            let cells = possible.iter().zip(&mut id_sums).map(|(possible, sum)| {
+                if *possible {
+                    *sum += id;
+                    "yes".to_string()
+                } else {
+                    "no".to_string()
+                }
+            });
+
+            [id.to_string()].into_iter().chain(cells).collect()
+        })
+        .collect::<Vec<Vec<_>>>();
+    rows.push(
+        ["sum of ids".to_string()]
+            .into_iter()
+            .chain(id_sums.iter().map(usize::to_string))
+            .collect(),
+    );
+
+    print_table(header, rows);
+}
+
+fn minimal(games: &[Game]) {
+    let overall = overall_minimal_bag(games);
+    let colors = colors(games);
+
+    let header = ["game"]
+        .into_iter()
+        .chain(colors.iter().copied())
+        .chain(["power"])
+        .map(str::to_string)
+        .collect();
+
+    let row = |name: String, bag: &Bag| {
+        [name]
+            .into_iter()
+            .chain(colors.iter().map(|color| bag.count(color).to_string()))
+            .chain([bag.power(colors.iter().copied()).to_string()])
+            .collect()
+    };
+
+    let mut rows = minimal_bags(games)
+        .iter()
+        .map(|(id, bag)| row(id.to_string(), bag))
+        .collect::<Vec<_>>();
+    rows.push(row("all".to_string(), &overall));
+
+    print_table(header, rows);
+}
+
+fn distribution(games: &[Game]) {
+    let header = ["color", "cubes", "draws"].map(str::to_string).to_vec();
+
+    let rows = draw_distribution(games)
+        .into_iter()
+        .flat_map(|(color, counts)| {
+            counts.into_iter().map(move |(count, draws)| {
+                vec![color.to_string(), count.to_string(), draws.to_string()]
+            })
+        })
+        .collect();
+
+    print_table(header, rows);
+}
+
+fn impossible(games: &[Game], bag: &Bag) {
+    let header = ["game", "round", "draws over the bag"]
+        .map(str::to_string)
+        .to_vec();
+
+    let rows = impossible_rounds(games, bag)
+        .into_iter()
+        .map(|(id, round_number, draws)| {
+            let draws = draws
+                .iter()
+                .map(|draw| draw.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            vec![id.to_string(), round_number.to_string(), draws]
+        })
+        .collect();
+
+    print_table(header, rows);
+}
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let command = args.next().unwrap_or_else(|| panic!("{USAGE}"));
+    let (mut bags, input) = parse_options(args);
+
+    let file_content = match input {
+        Some(path) => fs::read_to_string(path).unwrap(),
+        None => include_str!("../../input").to_string(),
+    };
+    let games = parse_games(&file_content).unwrap_or_else(|error| panic!("{error}"));
+
+    match command.as_str() {
+        "possible" | "impossible" if bags.is_empty() => bags.push(Bag::default()),
+        "minimal" | "distribution" if !bags.is_empty() => panic!("{USAGE}"),
+        _ => {}
+    }
+
+    match command.as_str() {
+        "possible" => possible(&games, &bags),
+        "impossible" => match &bags[..] {
+            [bag] => impossible(&games, bag),
+            _ => panic!("impossible takes a single bag"),
+        },
+        "minimal" => minimal(&games),
+        "distribution" => distribution(&games),
+        _ => panic!("{USAGE}"),
+    }
+}
@@ -1,71 +1,454 @@
-use std::collections::BTreeMap;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    error::Error,
+    fmt,
+    str::FromStr,
+};
 
 const RED: &str = "red";
 const GREEN: &str = "green";
 const BLUE: &str = "blue";
 
-fn common(file_content: &str, solver: fn(&str, usize) -> Option<usize>) -> usize {
-    file_content
-        .lines()
-        .enumerate()
-        .flat_map(|(index, line)| {
-            let (_, content) = line.split_once(": ").unwrap();
-            solver(content, index)
+// Parsed games always have at least one round and rounds at least one draw,
+// `Display` only gives back parsable text for values that keep it that way
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Draw {
+    pub count: usize,
+    pub color: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Round {
+    pub draws: Vec<Draw>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    pub id: usize,
+    pub rounds: Vec<Round>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SyntaxError {
+    MissingHeader,
+    InvalidNumber(String),
+    InvalidDraw(String),
+}
+
+impl fmt::Display for SyntaxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SyntaxError::MissingHeader => write!(f, "expected a \"Game N: \" header"),
+            SyntaxError::InvalidNumber(number) => write!(f, "invalid number {number:?}"),
+            SyntaxError::InvalidDraw(draw) => {
+                write!(f, "invalid draw {draw:?}, expected \"count color\"")
+            }
+        }
+    }
+}
+
+impl Error for SyntaxError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseGameError {
+    InvalidSyntax {
+        line_number: usize,
+        error: SyntaxError,
+    },
+    DuplicateId {
+        line_number: usize,
+        id: usize,
+    },
+    OutOfOrderId {
+        line_number: usize,
+        id: usize,
+        previous_id: usize,
+    },
+}
+
+impl fmt::Display for ParseGameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseGameError::InvalidSyntax { line_number, error } => {
+                write!(f, "invalid game on line {line_number}: {error}")
+            }
+            ParseGameError::DuplicateId { line_number, id } => {
+                write!(f, "duplicate game id {id} on line {line_number}")
+            }
+            ParseGameError::OutOfOrderId {
+                line_number,
+                id,
+                previous_id,
+            } => write!(
+                f,
+                "game id {id} on line {line_number} comes after game id {previous_id}"
+            ),
+        }
+    }
+}
+
+impl Error for ParseGameError {}
+
+// Only accepts numbers written the way `Display` writes them back, so that
+// "+3" or "03" don't break the round trip
+fn parse_count(s: &str) -> Result<usize, SyntaxError> {
+    let invalid = || SyntaxError::InvalidNumber(s.to_string());
+
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) || (s.len() > 1 && s.starts_with('0'))
+    {
+        return Err(invalid());
+    }
+
+    s.parse().map_err(|_| invalid())
+}
+
+impl FromStr for Draw {
+    type Err = SyntaxError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || SyntaxError::InvalidDraw(s.to_string());
+        let (count, color) = s.split_once(' ').ok_or_else(invalid)?;
+
+        if color.is_empty() || color.contains(' ') {
+            return Err(invalid());
+        }
+
+        Ok(Draw {
+            count: parse_count(count)?,
+            color: color.to_string(),
         })
-        .sum()
+    }
 }
 
-fn split_round(game: &str) -> impl Iterator<Item = (&str, usize)> {
-    game.split(", ").map(|card| {
-        let (count, color) = card.split_once(" ").unwrap();
-        (color, count.parse::<usize>().unwrap())
-    })
+impl FromStr for Round {
+    type Err = SyntaxError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Round {
+            draws: s.split(", ").map(str::parse).collect::<Result<_, _>>()?,
+        })
+    }
 }
 
-fn part_1_solver(content: &str, index: usize) -> Option<usize> {
-    let constraints = BTreeMap::from([(RED, 12), (GREEN, 13), (BLUE, 14)]);
+impl FromStr for Game {
+    type Err = SyntaxError;
 
-    let satisfies_contraints = content
-        .split("; ")
-        .flat_map(split_round)
-        .all(|(color, count)| count <= *constraints.get(color).unwrap());
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (id, content) = s
+            .strip_prefix("Game ")
+            .and_then(|s| s.split_once(": "))
+            .ok_or(SyntaxError::MissingHeader)?;
 
-    if satisfies_contraints {
-        Some(index + 1)
-    } else {
-        None
+        Ok(Game {
+            id: parse_count(id)?,
+            rounds: content
+                .split("; ")
+                .map(str::parse)
+                .collect::<Result<_, _>>()?,
+        })
     }
 }
 
-pub fn solve_part_1(file_content: &str) -> usize {
-    common(file_content, part_1_solver)
+impl fmt::Display for Draw {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.count, self.color)
+    }
+}
+
+impl fmt::Display for Round {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, draw) in self.draws.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{draw}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Game {}: ", self.id)?;
+
+        for (index, round) in self.rounds.iter().enumerate() {
+            if index > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{round}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Game {
+    pub fn draws(&self) -> impl Iterator<Item = &Draw> {
+        self.rounds.iter().flat_map(|round| &round.draws)
+    }
+
+    pub fn is_possible_with(&self, bag: &Bag) -> bool {
+        self.draws().all(|draw| bag.allows(draw))
+    }
+
+    // Smallest bag the game could have been played with, only holding the
+    // colors that were drawn
+    pub fn minimal_bag(&self) -> Bag {
+        let mut cubes = BTreeMap::new();
+
+        for draw in self.draws() {
+            let count = cubes.entry(draw.color.clone()).or_default();
+            *count = draw.count.max(*count);
+        }
+
+        Bag { cubes }
+    }
+
+    // One-based number of the first round drawing more cubes than the bag
+    // holds, with the draws responsible for it
+    pub fn first_impossible_round(&self, bag: &Bag) -> Option<(usize, Vec<&Draw>)> {
+        self.rounds.iter().enumerate().find_map(|(index, round)| {
+            let draws = round
+                .draws
+                .iter()
+                .filter(|draw| !bag.allows(draw))
+                .collect::<Vec<_>>();
+
+            if draws.is_empty() {
+                None
+            } else {
+                Some((index + 1, draws))
+            }
+        })
+    }
+}
+
+// Game ids must be strictly increasing, as they are in the puzzle input
+pub fn parse_games(file_content: &str) -> Result<Vec<Game>, ParseGameError> {
+    let mut games: Vec<Game> = vec![];
+
+    for (index, line) in file_content.lines().enumerate() {
+        let line_number = index + 1;
+        let game = line
+            .parse::<Game>()
+            .map_err(|error| ParseGameError::InvalidSyntax { line_number, error })?;
+
+        if let Some(previous) = games.last() {
+            let id = game.id;
+
+            if games.binary_search_by_key(&id, |game| game.id).is_ok() {
+                return Err(ParseGameError::DuplicateId { line_number, id });
+            }
+
+            if id < previous.id {
+                return Err(ParseGameError::OutOfOrderId {
+                    line_number,
+                    id,
+                    previous_id: previous.id,
+                });
+            }
+        }
+
+        games.push(game);
+    }
+
+    Ok(games)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseBagError {
+    InvalidEntry(String),
+    DuplicateColor(String),
+}
+
+impl fmt::Display for ParseBagError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseBagError::InvalidEntry(entry) => {
+                write!(f, "invalid bag entry {entry:?}, expected color=count")
+            }
+            ParseBagError::DuplicateColor(color) => write!(f, "color {color} is in the bag twice"),
+        }
+    }
+}
+
+impl Error for ParseBagError {}
+
+// Colors missing from the bag have no cubes at all
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bag {
+    cubes: BTreeMap<String, usize>,
+}
+
+impl Bag {
+    pub fn new<S: Into<String>>(cubes: impl IntoIterator<Item = (S, usize)>) -> Self {
+        Self {
+            cubes: cubes
+                .into_iter()
+                .map(|(color, count)| (color.into(), count))
+                .collect(),
+        }
+    }
+
+    pub fn count(&self, color: &str) -> usize {
+        self.cubes.get(color).copied().unwrap_or_default()
+    }
+
+    pub fn cubes(&self) -> impl Iterator<Item = (&str, usize)> {
+        self.cubes
+            .iter()
+            .map(|(color, count)| (color.as_str(), *count))
+    }
+
+    pub fn allows(&self, draw: &Draw) -> bool {
+        draw.count <= self.count(&draw.color)
+    }
+
+    // Product of the cubes of every given color, a color missing from the bag
+    // makes it zero
+    pub fn power<'a>(&self, colors: impl IntoIterator<Item = &'a str>) -> usize {
+        colors.into_iter().map(|color| self.count(color)).product()
+    }
+}
+
+impl Default for Bag {
+    fn default() -> Self {
+        Self::new([(RED, 12), (GREEN, 13), (BLUE, 14)])
+    }
+}
+
+// Same syntax as the `--bag` argument, e.g. "red=12,green=13,blue=14"
+impl FromStr for Bag {
+    type Err = ParseBagError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut cubes = BTreeMap::new();
+
+        for entry in s.split(',') {
+            let (color, count) = entry
+                .split_once('=')
+                .and_then(|(color, count)| Some((color.trim(), count.trim().parse().ok()?)))
+                .filter(|(color, _)| !color.is_empty())
+                .ok_or_else(|| ParseBagError::InvalidEntry(entry.to_string()))?;
+
+            if cubes.insert(color.to_string(), count).is_some() {
+                return Err(ParseBagError::DuplicateColor(color.to_string()));
+            }
+        }
+
+        Ok(Self { cubes })
+    }
+}
+
+impl fmt::Display for Bag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, (color, count)) in self.cubes().enumerate() {
+            if index > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{color}={count}")?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn colors(games: &[Game]) -> BTreeSet<&str> {
+    games
+        .iter()
+        .flat_map(Game::draws)
+        .map(|draw| draw.color.as_str())
+        .collect()
+}
+
+// For every game, whether it is possible with each of the bags, in order
+pub fn possible_games(games: &[Game], bags: &[Bag]) -> Vec<(usize, Vec<bool>)> {
+    games
+        .iter()
+        .map(|game| {
+            let possible = bags.iter().map(|bag| game.is_possible_with(bag)).collect();
+            (game.id, possible)
+        })
+        .collect()
+}
+
+pub fn minimal_bags(games: &[Game]) -> Vec<(usize, Bag)> {
+    games
+        .iter()
+        .map(|game| (game.id, game.minimal_bag()))
+        .collect()
 }
 
-fn part_2_solver(content: &str, _: usize) -> Option<usize> {
-    let answer = content
-        .split("; ")
-        .map(split_round)
-        .map(Iterator::collect::<BTreeMap<_, _>>)
-        .fold(
-            BTreeMap::from([(RED, 0), (GREEN, 0), (BLUE, 0)]),
-            |mut acc, game| {
-                for (color, count) in game {
-                    if let Some(x) = acc.get_mut(color) {
-                        *x = count.max(*x);
-                    }
-                }
+// Smallest bag every game could have been played with
+pub fn overall_minimal_bag(games: &[Game]) -> Bag {
+    let mut cubes = BTreeMap::new();
 
-                acc
-            },
-        )
-        .values()
-        .product::<usize>();
+    for (color, count) in games.iter().flat_map(|game| game.minimal_bag().cubes) {
+        let max = cubes.entry(color).or_default();
+        *max = count.max(*max);
+    }
+
+    Bag { cubes }
+}
+
+// For every color, how many draws took out each number of cubes
+pub fn draw_distribution(games: &[Game]) -> BTreeMap<&str, BTreeMap<usize, usize>> {
+    let mut distribution = BTreeMap::<_, BTreeMap<_, _>>::new();
+
+    for draw in games.iter().flat_map(Game::draws) {
+        *distribution
+            .entry(draw.color.as_str())
+            .or_default()
+            .entry(draw.count)
+            .or_default() += 1;
+    }
 
-    Some(answer)
+    distribution
+}
+
+pub fn impossible_rounds<'a>(games: &'a [Game], bag: &Bag) -> Vec<(usize, usize, Vec<&'a Draw>)> {
+    games
+        .iter()
+        .filter_map(|game| {
+            let (round_number, draws) = game.first_impossible_round(bag)?;
+            Some((game.id, round_number, draws))
+        })
+        .collect()
+}
+
+fn common(file_content: &str, solver: impl Fn(&Game) -> Option<usize>) -> usize {
+    parse_games(file_content)
+        .unwrap_or_else(|error| panic!("{error}"))
+        .iter()
+        .flat_map(solver)
+        .sum()
 }
 
+pub fn solve_part_1_with_bag(file_content: &str, bag: &Bag) -> usize {
+    common(file_content, |game| {
+        if game.is_possible_with(bag) {
+            Some(game.id)
+        } else {
+            None
+        }
+    })
+}
+
+pub fn solve_part_1(file_content: &str) -> usize {
+    solve_part_1_with_bag(file_content, &Bag::default())
+}
+
+// The power of a game is over every color of the log, so a game that never
+// draws one of them has a power of zero
 pub fn solve_part_2(file_content: &str) -> usize {
-    common(file_content, part_2_solver)
+    let games = parse_games(file_content).unwrap_or_else(|error| panic!("{error}"));
+    let colors = colors(&games);
+
+    games
+        .iter()
+        .map(|game| game.minimal_bag().power(colors.iter().copied()))
+        .sum()
 }
 
 #[cfg(test)]
@@ -78,13 +461,266 @@ Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
 Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
 Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"#;
 
+    #[test]
+    fn test_parse_game() {
+        let result = "Game 12: 3 blue, 4 red; 2 green".parse::<Game>();
+        assert_eq!(
+            result,
+            Ok(Game {
+                id: 12,
+                rounds: vec![
+                    Round {
+                        draws: vec![
+                            Draw {
+                                count: 3,
+                                color: BLUE.to_string()
+                            },
+                            Draw {
+                                count: 4,
+                                color: RED.to_string()
+                            },
+                        ]
+                    },
+                    Round {
+                        draws: vec![Draw {
+                            count: 2,
+                            color: GREEN.to_string()
+                        }]
+                    },
+                ]
+            })
+        );
+
+        let result = "Game 1 3 blue".parse::<Game>();
+        assert_eq!(result, Err(SyntaxError::MissingHeader));
+
+        let result = "Round 1: 3 blue".parse::<Game>();
+        assert_eq!(result, Err(SyntaxError::MissingHeader));
+
+        let result = "Game x: 3 blue".parse::<Game>();
+        assert_eq!(result, Err(SyntaxError::InvalidNumber("x".to_string())));
+
+        let result = "Game 01: 3 blue".parse::<Game>();
+        assert_eq!(result, Err(SyntaxError::InvalidNumber("01".to_string())));
+
+        let result = "Game 1: +3 blue".parse::<Game>();
+        assert_eq!(result, Err(SyntaxError::InvalidNumber("+3".to_string())));
+
+        let result = "Game 1: three blue".parse::<Game>();
+        assert_eq!(result, Err(SyntaxError::InvalidNumber("three".to_string())));
+
+        let result = "Game 1: 3 blue,4 red".parse::<Game>();
+        assert_eq!(
+            result,
+            Err(SyntaxError::InvalidDraw("3 blue,4 red".to_string()))
+        );
+
+        let result = "Game 1: 3".parse::<Game>();
+        assert_eq!(result, Err(SyntaxError::InvalidDraw("3".to_string())));
+
+        let result = "Game 1: ".parse::<Game>();
+        assert_eq!(result, Err(SyntaxError::InvalidDraw(String::new())));
+
+        let result = "Game 1: 3 dark blue".parse::<Game>();
+        assert_eq!(
+            result,
+            Err(SyntaxError::InvalidDraw("3 dark blue".to_string()))
+        );
+        assert!("Game 1: 0 blue".parse::<Game>().is_ok());
+    }
+
+    #[test]
+    fn test_parse_games() {
+        let result = parse_games(SAMPLE_DATA).unwrap();
+        assert_eq!(
+            result.iter().map(|game| game.id).collect::<Vec<_>>(),
+            vec![1, 2, 3, 4, 5]
+        );
+
+        let result = parse_games("Game 1: 1 red\nGame 3: 1 red\nGame 7: 2 blue");
+        assert_eq!(result.map(|games| games.len()), Ok(3));
+
+        let result = parse_games("Game 1: 1 red\nGame 3: 1 red\nGame 1: 2 blue");
+        assert_eq!(
+            result,
+            Err(ParseGameError::DuplicateId {
+                line_number: 3,
+                id: 1
+            })
+        );
+
+        let result = parse_games("Game 1: 1 red\nGame 3: 1 red\nGame 2: 2 blue");
+        assert_eq!(
+            result,
+            Err(ParseGameError::OutOfOrderId {
+                line_number: 3,
+                id: 2,
+                previous_id: 3
+            })
+        );
+
+        let result = parse_games("Game 1: 1 red\n\nGame 2: 2 blue");
+        assert_eq!(
+            result,
+            Err(ParseGameError::InvalidSyntax {
+                line_number: 2,
+                error: SyntaxError::MissingHeader
+            })
+        );
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        let result = parse_games(SAMPLE_DATA)
+            .unwrap()
+            .iter()
+            .map(Game::to_string)
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert_eq!(result, SAMPLE_DATA);
+    }
+
     #[test]
     fn test_part_1() {
         assert_eq!(solve_part_1(SAMPLE_DATA), 8);
+        assert_eq!(solve_part_1("Game 7: 3 blue\nGame 9: 15 red"), 7);
+    }
+
+    #[test]
+    fn test_parse_bag() {
+        let result = "red=12,green=13,blue=14".parse::<Bag>();
+        assert_eq!(result, Ok(Bag::default()));
+
+        let result = "purple = 3, red=1".parse::<Bag>().unwrap();
+        assert_eq!(result.count("purple"), 3);
+        assert_eq!(result.count("red"), 1);
+        assert_eq!(result.count("blue"), 0);
+        assert_eq!(result.to_string(), "purple=3,red=1");
+
+        let result = "red=1,red=2".parse::<Bag>();
+        assert_eq!(result, Err(ParseBagError::DuplicateColor(RED.to_string())));
+
+        let result = "red=1,blue".parse::<Bag>();
+        assert_eq!(result, Err(ParseBagError::InvalidEntry(BLUE.to_string())));
+
+        assert!("=1".parse::<Bag>().is_err());
+        assert!("red=x".parse::<Bag>().is_err());
+        assert!("".parse::<Bag>().is_err());
+    }
+
+    #[test]
+    fn test_minimal_bag() {
+        let game = "Game 1: 3 blue, 4 red; 1 red, 2 purple, 6 blue"
+            .parse::<Game>()
+            .unwrap();
+        let result = game.minimal_bag();
+        assert_eq!(result, Bag::new([(BLUE, 6), (RED, 4), ("purple", 2)]));
+        assert_eq!(result.power([BLUE, RED, "purple"]), 48);
+        assert_eq!(result.power([BLUE, RED, "purple", GREEN]), 0);
+    }
+
+    #[test]
+    fn test_first_impossible_round() {
+        let games = parse_games(SAMPLE_DATA).unwrap();
+
+        let result = games[2].first_impossible_round(&Bag::default());
+        assert_eq!(
+            result,
+            Some((
+                1,
+                vec![&Draw {
+                    count: 20,
+                    color: RED.to_string()
+                }]
+            ))
+        );
+
+        let result = games[0].first_impossible_round(&Bag::default());
+        assert_eq!(result, None);
+
+        let bag = "red=4".parse().unwrap();
+        let result = games[0].first_impossible_round(&bag).unwrap();
+        assert_eq!(result.0, 1);
+        assert_eq!(result.1[0].color, BLUE);
+    }
+
+    #[test]
+    fn test_possible_games() {
+        let games = parse_games(SAMPLE_DATA).unwrap();
+        let bags = [Bag::default(), "red=20,green=13,blue=15".parse().unwrap()];
+
+        let result = possible_games(&games, &bags);
+        assert_eq!(
+            result,
+            vec![
+                (1, vec![true, true]),
+                (2, vec![true, true]),
+                (3, vec![false, true]),
+                (4, vec![false, true]),
+                (5, vec![true, true]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_minimal_bags() {
+        let games = parse_games(SAMPLE_DATA).unwrap();
+
+        let result = minimal_bags(&games)
+            .into_iter()
+            .map(|(id, bag)| (id, bag.power(colors(&games))))
+            .collect::<Vec<_>>();
+        assert_eq!(result, vec![(1, 48), (2, 12), (3, 1560), (4, 630), (5, 36)]);
+
+        let result = overall_minimal_bag(&games);
+        assert_eq!(result, Bag::new([(RED, 20), (GREEN, 13), (BLUE, 15)]));
+    }
+
+    #[test]
+    fn test_draw_distribution() {
+        let games = parse_games("Game 1: 3 blue, 4 red; 3 blue\nGame 2: 1 red").unwrap();
+
+        let result = draw_distribution(&games);
+        assert_eq!(
+            result,
+            BTreeMap::from([
+                (BLUE, BTreeMap::from([(3, 2)])),
+                (RED, BTreeMap::from([(1, 1), (4, 1)])),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_impossible_rounds() {
+        let games = parse_games(SAMPLE_DATA).unwrap();
+
+        let result = impossible_rounds(&games, &Bag::default())
+            .into_iter()
+            .map(|(id, round_number, draws)| (id, round_number, draws.len()))
+            .collect::<Vec<_>>();
+        assert_eq!(result, vec![(3, 1, 1), (4, 3, 2)]);
+    }
+
+    #[test]
+    fn test_part_1_with_bag() {
+        const DATA: &str = "Game 1: 2 purple, 1 red\nGame 2: 3 purple\nGame 3: 1 blue";
+
+        let bag = "red=12,green=13,blue=14,purple=2".parse().unwrap();
+        assert_eq!(solve_part_1_with_bag(DATA, &bag), 1 + 3);
+
+        assert_eq!(solve_part_1_with_bag(DATA, &Bag::default()), 3);
+
+        let bag = "red=20,green=20,blue=20".parse().unwrap();
+        assert_eq!(solve_part_1_with_bag(SAMPLE_DATA, &bag), 15);
     }
 
     #[test]
     fn test_part_2() {
         assert_eq!(solve_part_2(SAMPLE_DATA), 2286);
+        assert_eq!(solve_part_2("Game 1: 2 purple, 3 red; 4 purple"), 12);
+        assert_eq!(
+            solve_part_2("Game 1: 1 red, 1 green\nGame 2: 1 blue, 1 red, 1 green"),
+            1
+        );
     }
 }
@@ -19,7 +19,14 @@ generate_script() {
 generate_script 1
 generate_script 2
 
-# create lib file
+# create lib file, with the samples of the puzzle page when one is given
+if [ -n "$1" ]; then
+    if cargo run --quiet --manifest-path aoc/Cargo.toml -- samples "$1" day${next_day}; then
+        exit
+    fi
+    echo "Could not extract the samples of $1, creating an empty lib file instead" >&2
+fi
+
 echo 'pub fn solve_part_1(file_content: &str) -> usize {
     0
 }